@@ -44,24 +44,47 @@
 extern crate alloc;
 
 use core::cmp;
+use core::ops::Add;
 
 #[cfg(feature = "alloc")]
-use alloc::{borrow::ToOwned, boxed::Box};
+use alloc::{borrow::ToOwned, boxed::Box, collections::BTreeMap, string::String, vec::Vec};
 
 mod slice;
+pub use crate::slice::partition_point;
+pub use crate::slice::{array_chunks, ArrayChunks};
+pub use crate::slice::{array_windows_mut, ArrayWindowsMut};
+pub use crate::slice::{chunks_mut, ChunksMut};
+pub use crate::slice::{cycle_slice, CycleSlice};
+pub use crate::slice::{for_each_window_mut, for_each_window_mut_rev};
+pub use crate::slice::{group_adjacent_mut, GroupAdjacentMut};
+pub use crate::slice::{group_runs_mut, GroupRunsMut};
+pub use crate::slice::{rchunks_mut, RChunksMut};
+pub use crate::slice::{rotate_left_mut, RotateLeftMut};
+pub use crate::slice::{split_mut, SplitMut};
+pub use crate::slice::{transpose_slice_mut, TransposeSliceMut};
+pub use crate::slice::{windows, Windows};
 pub use crate::slice::{windows_mut, WindowsMut};
+pub use crate::slice::{windows_mut_step, WindowsMutStep};
 
 mod sources;
+#[cfg(feature = "alloc")]
+pub use crate::sources::{chain_many, ChainMany};
 pub use crate::sources::{convert, Convert};
+pub use crate::sources::{convert_into_ref, ConvertIntoRef};
 pub use crate::sources::{convert_mut, ConvertMut};
 pub use crate::sources::{convert_ref, ConvertRef};
 pub use crate::sources::{empty, Empty};
 pub use crate::sources::{from_fn, FromFn};
+pub use crate::sources::{iterate, Iterate};
 pub use crate::sources::{once, Once};
 pub use crate::sources::{once_with, OnceWith};
+pub use crate::sources::{range, Range, Step};
 pub use crate::sources::{repeat, Repeat};
+pub use crate::sources::{repeat_n, RepeatN};
 pub use crate::sources::{repeat_with, RepeatWith};
 pub use crate::sources::{successors, Successors};
+pub use crate::sources::{successors_bounded, SuccessorsBounded};
+pub use crate::sources::{successors_n, SuccessorsN};
 
 /// An interface for dealing with streaming iterators.
 pub trait StreamingIterator {
@@ -104,6 +127,30 @@ pub trait StreamingIterator {
         self.get().is_none()
     }
 
+    /// Advances the iterator by `n` elements.
+    ///
+    /// On success, the iterator is left on the `n`th element, exactly as if `advance` had been
+    /// called `n` times. If the iterator runs out first, `Err(k)` is returned, where `k` is the
+    /// number of advancements that could not be made.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut it = convert([0, 1, 2]);
+    /// assert_eq!(it.advance_by(2), Ok(()));
+    /// assert_eq!(it.get(), Some(&1));
+    /// assert_eq!(it.advance_by(3), Err(2));
+    /// ```
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), usize> {
+        for i in 0..n {
+            self.advance();
+            if self.is_done() {
+                return Err(n - i);
+            }
+        }
+        Ok(())
+    }
+
     /// Determines if all elements of the iterator satisfy a predicate.
     #[inline]
     fn all<F>(&mut self, mut f: F) -> bool
@@ -156,6 +203,155 @@ pub trait StreamingIterator {
         }
     }
 
+    /// Creates an iterator over runs of consecutive elements which map to equal keys.
+    ///
+    /// Each run is yielded as a `Group`, which is itself a streaming iterator over the elements
+    /// of the run. The groups share the position of the underlying iterator, so a group is
+    /// consumed through `StreamingIteratorMut::get_mut` or `next_mut`, and advancing to the next
+    /// group skips whatever remains of the current one.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator, StreamingIteratorMut};
+    /// let mut it = convert([1, 1, 2, 3, 3]).chunk_by(|&i| i);
+    /// let mut lens = vec![];
+    /// while let Some(group) = it.next_mut() {
+    ///     lens.push(group.count());
+    /// }
+    /// assert_eq!(lens, [2, 1, 2]);
+    /// ```
+    #[inline]
+    fn chunk_by<K, F>(self, f: F) -> ChunkBy<Self, K, F>
+    where
+        Self: Sized,
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        ChunkBy {
+            group: Group {
+                it: self,
+                f,
+                key: None,
+                pending: false,
+                ended: true,
+            },
+            first: true,
+            active: false,
+        }
+    }
+
+    /// Creates an iterator which only yields elements that differ significantly from the last
+    /// element it yielded.
+    ///
+    /// The first element is always yielded. After that, an element is yielded only if
+    /// `metric(last, element)` is greater than `threshold`, where `last` is a clone of the last
+    /// yielded element. Elements which are skipped don't replace `last`, so slow drifts are still
+    /// reported once they add up.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let readings = [10.0, 10.2, 9.9, 12.0, 12.1, 8.0];
+    /// let mut it = convert(readings).changes_by(1.0, |a: &f64, b: &f64| (a - b).abs());
+    /// assert_eq!(it.next(), Some(&10.0));
+    /// assert_eq!(it.next(), Some(&12.0));
+    /// assert_eq!(it.next(), Some(&8.0));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    #[inline]
+    fn changes_by<F, D>(self, threshold: D, metric: F) -> ChangesBy<Self, F, D>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, &Self::Item) -> D,
+        D: PartialOrd,
+    {
+        ChangesBy {
+            it: self,
+            metric,
+            threshold,
+            last: None,
+        }
+    }
+
+    /// Creates an iterator over non-overlapping chunks of `size` elements, each of which is
+    /// collected into an owned buffer.
+    ///
+    /// The elements are converted with `to_owned` as they are consumed. The last chunk may be
+    /// shorter than `size` if the number of elements is not a multiple of it.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert_ref, StreamingIterator};
+    /// let mut chunks = convert_ref(["a", "b", "c"]).chunks(2);
+    /// assert_eq!(chunks.next(), Some(&[String::from("a"), String::from("b")][..]));
+    /// assert_eq!(chunks.next(), Some(&[String::from("c")][..]));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn chunks(self, size: usize) -> Chunks<Self>
+    where
+        Self: Sized,
+        Self::Item: ToOwned,
+    {
+        assert!(size != 0, "size is zero");
+        Chunks {
+            it: self,
+            size,
+            buf: Vec::with_capacity(size),
+            exhausted: false,
+        }
+    }
+
+    /// Creates an iterator over non-overlapping chunks of `size` elements, each of which is itself
+    /// a streaming iterator.
+    ///
+    /// Only full chunks are yielded. The number of trailing elements which do not fill a chunk is
+    /// available from `ChunksExact::remainder_len`, and those elements are never consumed. Since
+    /// this must be known up front, the iterator must know its exact length.
+    ///
+    /// Each chunk borrows the position of the underlying iterator, so it must be accessed through
+    /// `get_mut` in order to iterate over it, and advancing to the next chunk skips whatever
+    /// remains of the current one.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator, StreamingIteratorMut};
+    /// let mut chunks = convert([1, 2, 3, 4, 5, 6, 7]).chunks_exact(3);
+    /// assert_eq!(chunks.remainder_len(), 1);
+    /// let mut sums = vec![];
+    /// while let Some(chunk) = chunks.next_mut() {
+    ///     sums.push(chunk.fold(0, |acc, i| acc + i));
+    /// }
+    /// assert_eq!(sums, [6, 15]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    #[inline]
+    fn chunks_exact(self, size: usize) -> ChunksExact<Self>
+    where
+        Self: Sized + ExactSizeStreamingIterator,
+    {
+        assert!(size != 0, "size is zero");
+        let len = self.len();
+        ChunksExact {
+            chunk: Take {
+                it: self,
+                n: 0,
+                done: false,
+            },
+            size,
+            chunks: len / size,
+            rem: len % size,
+            active: false,
+        }
+    }
+
     /// Produces a normal, non-streaming, iterator by cloning the elements of this iterator.
     #[inline]
     fn cloned(self) -> Cloned<Self>
@@ -166,6 +362,86 @@ pub trait StreamingIterator {
         Cloned(self)
     }
 
+    /// Lexicographically compares the elements of this iterator with those of another.
+    ///
+    /// If one iterator is a prefix of the other, the shorter one compares as less.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// use core::cmp::Ordering;
+    /// assert_eq!(convert([1, 2]).cmp(convert([1, 3])), Ordering::Less);
+    /// assert_eq!(convert([1, 2]).cmp(convert([1])), Ordering::Greater);
+    /// assert_eq!(convert([1, 2]).cmp(convert([1, 2])), Ordering::Equal);
+    /// ```
+    #[inline]
+    fn cmp<J>(mut self, mut other: J) -> cmp::Ordering
+    where
+        Self: Sized,
+        J: StreamingIterator<Item = Self::Item>,
+        Self::Item: Ord,
+    {
+        loop {
+            match (self.next(), other.next()) {
+                (Some(a), Some(b)) => match a.cmp(b) {
+                    cmp::Ordering::Equal => {}
+                    ordering => return ordering,
+                },
+                (Some(_), None) => return cmp::Ordering::Greater,
+                (None, Some(_)) => return cmp::Ordering::Less,
+                (None, None) => return cmp::Ordering::Equal,
+            }
+        }
+    }
+
+    /// Collects owned copies of the elements of the iterator into a collection.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert_ref, StreamingIterator};
+    /// let words = ["hello", "world"];
+    /// let owned = convert_ref(words).collect::<Vec<String>>();
+    /// assert_eq!(owned, ["hello", "world"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn collect<C>(self) -> C
+    where
+        Self: Sized,
+        Self::Item: ToOwned,
+        C: Default + Extend<<Self::Item as ToOwned>::Owned>,
+    {
+        self.fold(C::default(), |mut c, item| {
+            c.extend(Some(item.to_owned()));
+            c
+        })
+    }
+
+    /// Collects the characters of the iterator into a `String`.
+    ///
+    /// The string is preallocated with one byte per character according to `size_hint`.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert_ref, StreamingIterator};
+    /// let chars = ['a', '1', 'b', '2'];
+    /// let s = convert_ref(&chars).filter(|c| c.is_alphabetic()).collect_string();
+    /// assert_eq!(s, "ab");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn collect_string(self) -> String
+    where
+        Self: Sized + StreamingIterator<Item = char>,
+    {
+        let s = String::with_capacity(self.size_hint().0);
+        self.fold(s, |mut s, &c| {
+            s.push(c);
+            s
+        })
+    }
+
     /// Produces a normal, non-streaming, iterator by copying the elements of this iterator.
     #[inline]
     fn copied(self) -> Copied<Self>
@@ -185,6 +461,196 @@ pub trait StreamingIterator {
         self.fold(0, |count, _| count + 1)
     }
 
+    /// Counts the elements of the iterator which satisfy a predicate.
+    #[inline]
+    fn count_where<F>(self, mut f: F) -> usize
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.fold(0, |count, item| count + f(item) as usize)
+    }
+
+    /// Counts the elements of the iterator by the key computed for each of them.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let counts = convert([1, 2, 3, 4, 5]).counts_by(|&i| i % 2 == 0);
+    /// assert_eq!(counts[&true], 2);
+    /// assert_eq!(counts[&false], 3);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn counts_by<K, F>(self, mut f: F) -> BTreeMap<K, usize>
+    where
+        Self: Sized,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.fold(BTreeMap::new(), |mut counts, item| {
+            *counts.entry(f(item)).or_insert(0) += 1;
+            counts
+        })
+    }
+
+    /// Creates an iterator which repeats the elements of this iterator endlessly.
+    ///
+    /// The iterator is cloned at creation and whenever it runs out of elements, so it must be
+    /// `Clone`. If it is empty, the cycle is empty as well.
+    #[inline]
+    fn cycle(self) -> Cycle<Self>
+    where
+        Self: Sized + Clone,
+    {
+        Cycle {
+            orig: self.clone(),
+            it: self,
+        }
+    }
+
+    /// Creates an iterator which skips elements equal to the element yielded before them.
+    ///
+    /// A clone of the most recently yielded element is kept in order to compare it against the
+    /// following elements, which is why `Clone` is required.
+    ///
+    /// When iterating from both ends, each end compares against the element it yielded last, so
+    /// consecutive duplicates are collapsed within each direction of traversal. A run of
+    /// duplicates that is reached from both ends may be yielded once from each end.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut it = convert([1, 1, 2, 3, 3, 3, 1]).dedup();
+    /// assert_eq!(it.next(), Some(&1));
+    /// assert_eq!(it.next(), Some(&2));
+    /// assert_eq!(it.next(), Some(&3));
+    /// assert_eq!(it.next(), Some(&1));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    #[inline]
+    fn dedup(self) -> Dedup<Self>
+    where
+        Self: Sized,
+        Self::Item: PartialEq + Clone,
+    {
+        Dedup {
+            it: self,
+            front: None,
+            back: None,
+        }
+    }
+
+    /// Creates an iterator which skips elements which a closure considers the same as the element
+    /// yielded before them.
+    ///
+    /// The closure is called with the previously yielded element and the candidate element, in
+    /// that order. A clone of the most recently yielded element is kept in order to compare it
+    /// against the following elements, which is why `Clone` is required.
+    #[inline]
+    fn dedup_by<F>(self, same: F) -> DedupBy<Self, F>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        DedupBy {
+            it: self,
+            same,
+            front: None,
+            back: None,
+        }
+    }
+
+    /// Creates an iterator which skips elements which map to the same key as the element yielded
+    /// before them.
+    ///
+    /// Only the key of the most recently yielded element is kept, so unlike `dedup` the elements
+    /// themselves need not be `Clone`.
+    #[inline]
+    fn dedup_by_key<K, F>(self, f: F) -> DedupByKey<Self, K, F>
+    where
+        Self: Sized,
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        DedupByKey {
+            it: self,
+            f,
+            front: None,
+            back: None,
+        }
+    }
+
+    /// Produces a normal, non-streaming, iterator which run-length encodes the elements of this
+    /// iterator.
+    ///
+    /// Each run of consecutive equal elements is yielded once as a clone of its first element
+    /// together with the length of the run, which is always at least 1.
+    #[inline]
+    fn distinct_runs(self) -> DistinctRuns<Self>
+    where
+        Self: Sized,
+        Self::Item: PartialEq + Clone,
+    {
+        DistinctRuns {
+            it: self,
+            run: None,
+            done: false,
+        }
+    }
+
+    /// Determines if the elements of this iterator are equal to those of another.
+    ///
+    /// Both iterators are advanced in lockstep, so iteration stops at the first mismatch.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, convert_ref, StreamingIterator};
+    /// assert!(convert([1, 2]).eq(convert_ref(&[1, 2])));
+    /// assert!(!convert([1, 2]).eq(convert([1, 2, 3])));
+    /// ```
+    #[inline]
+    fn eq<J>(mut self, mut other: J) -> bool
+    where
+        Self: Sized,
+        J: StreamingIterator,
+        Self::Item: PartialEq<J::Item>,
+    {
+        loop {
+            match (self.next(), other.next()) {
+                (Some(a), Some(b)) => {
+                    if a != b {
+                        return false;
+                    }
+                }
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+
+    /// Creates an iterator which keeps track of the index of the current element.
+    ///
+    /// The elements themselves are yielded unchanged; the index of the current element is
+    /// available through `Enumerate::index`.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut it = convert(["a", "b"]).enumerate();
+    /// it.advance();
+    /// while let Some(line) = it.get() {
+    ///     println!("{}: {}", it.index(), line);
+    ///     it.advance();
+    /// }
+    /// ```
+    #[inline]
+    fn enumerate(self) -> Enumerate<Self>
+    where
+        Self: Sized,
+    {
+        Enumerate { it: self, count: 0 }
+    }
+
     /// Creates an iterator which uses a closure to determine if an element should be yielded.
     #[inline]
     fn filter<F>(self, f: F) -> Filter<Self, F>
@@ -192,7 +658,11 @@ pub trait StreamingIterator {
         Self: Sized,
         F: FnMut(&Self::Item) -> bool,
     {
-        Filter { it: self, f }
+        Filter {
+            it: self,
+            f,
+            done: true,
+        }
     }
 
     /// Creates an iterator which both filters and maps by applying a closure to elements.
@@ -222,6 +692,46 @@ pub trait StreamingIterator {
             it: self,
             f,
             sub_iter: None,
+            back_iter: None,
+            at_back: false,
+        }
+    }
+
+    /// Creates an iterator which flattens slices borrowed from elements by a closure.
+    ///
+    /// Unlike `flat_map`, the closure returns a slice borrowed from the element it is given, for
+    /// example one of its fields. The element must therefore stay in place while the items of its
+    /// slice are yielded, so this iterator only advances the underlying iterator once the current
+    /// slice is exhausted. The closure is called again each time the slice is accessed, so it
+    /// should be cheap, such as a field access.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// struct Line {
+    ///     words: Vec<&'static str>,
+    /// }
+    ///
+    /// let lines = vec![
+    ///     Line { words: vec!["hello", "world"] },
+    ///     Line { words: vec![] },
+    ///     Line { words: vec!["bye"] },
+    /// ];
+    /// let mut it = convert(lines).flat_map_ref(|line| &line.words[..]);
+    /// assert_eq!(it.next(), Some(&"hello"));
+    /// assert_eq!(it.next(), Some(&"world"));
+    /// assert_eq!(it.next(), Some(&"bye"));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    #[inline]
+    fn flat_map_ref<B, F>(self, f: F) -> FlatMapRef<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&Self::Item) -> &[B],
+    {
+        FlatMapRef {
+            it: self,
+            f,
+            index: None,
         }
     }
 
@@ -257,6 +767,35 @@ pub trait StreamingIterator {
         (*self).get()
     }
 
+    /// Applies a closure to the elements of the iterator, returning the first non-`None` result.
+    ///
+    /// The iterator is left positioned on the element which produced the result.
+    #[inline]
+    fn find_map<B, F>(&mut self, mut f: F) -> Option<B>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> Option<B>,
+    {
+        while let Some(item) = self.next() {
+            if let Some(b) = f(item) {
+                return Some(b);
+            }
+        }
+        None
+    }
+
+    /// Advances the iterator by one element, returning a clone of it.
+    ///
+    /// This is equivalent to `next().cloned()`. The iterator is left positioned after the
+    /// returned element, so further iteration continues from the second element.
+    #[inline]
+    fn first(&mut self) -> Option<Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        self.next().cloned()
+    }
+
     /// Creates an iterator which is "well behaved" at the beginning and end of iteration.
     ///
     /// The behavior of calling `get` before iteration has been started, and of continuing to call
@@ -285,6 +824,104 @@ pub trait StreamingIterator {
         Inspect { it: self, f }
     }
 
+    /// Creates an iterator which alternates between the elements of this iterator and another
+    /// one, starting with this one.
+    ///
+    /// Once either iterator is exhausted, the remaining elements of the other are yielded in
+    /// order.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut it = convert([1, 2, 3]).interleave(convert([10]));
+    /// assert_eq!(it.next(), Some(&1));
+    /// assert_eq!(it.next(), Some(&10));
+    /// assert_eq!(it.next(), Some(&2));
+    /// assert_eq!(it.next(), Some(&3));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    #[inline]
+    fn interleave<J>(self, other: J) -> Interleave<Self, J>
+    where
+        Self: Sized,
+        J: StreamingIterator<Item = Self::Item>,
+    {
+        Interleave {
+            a: self,
+            b: other,
+            a_done: false,
+            b_done: false,
+            state: MergeState::Init,
+        }
+    }
+
+    /// Formats every element with its `Debug` implementation, separating them with `sep`.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// assert_eq!(convert(["a", "b"]).join_debug(", "), r#""a", "b""#);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn join_debug(self, sep: &str) -> String
+    where
+        Self: Sized,
+        Self::Item: core::fmt::Debug,
+    {
+        use core::fmt::Write;
+
+        let mut first = true;
+        self.fold(String::new(), |mut out, item| {
+            if !first {
+                out.push_str(sep);
+            }
+            first = false;
+            let _ = write!(out, "{:?}", item);
+            out
+        })
+    }
+
+    /// Consumes the iterator, returning a clone of its last element.
+    ///
+    /// Since the last element is only known once the iterator has been advanced past it, every
+    /// element is cloned along the way. For a `DoubleEndedStreamingIterator`,
+    /// `next_back().cloned()` avoids this.
+    #[inline]
+    fn last(self) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        self.fold(None, |_, item| Some(item.clone()))
+    }
+
+    /// Creates an iterator which yields each element paired with the element following it.
+    ///
+    /// The iterator buffers one element ahead: each element is cloned out of the underlying
+    /// iterator so that it can be held while the next one is read, so this costs a clone per
+    /// element. The last element is paired with `None`.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut it = convert([1, 2]).lookahead();
+    /// assert_eq!(it.next(), Some(&(1, Some(2))));
+    /// assert_eq!(it.next(), Some(&(2, None)));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    #[inline]
+    fn lookahead(self) -> Lookahead<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Lookahead {
+            it: self,
+            item: None,
+            first: true,
+        }
+    }
+
     /// Creates an iterator which transforms elements of this iterator by passing them to a closure.
     #[inline]
     fn map<B, F>(self, f: F) -> Map<Self, B, F>
@@ -325,1703 +962,2180 @@ pub trait StreamingIterator {
         MapRef { it: self, f }
     }
 
-    /// Consumes the first `n` elements of the iterator, returning the next one.
+    /// Creates an iterator which both yields and maps elements with a closure until it returns
+    /// `None`.
     #[inline]
-    fn nth(&mut self, n: usize) -> Option<&Self::Item> {
-        for _ in 0..n {
-            self.advance();
-            if self.is_done() {
-                return None;
-            }
+    fn map_while<B, F>(self, f: F) -> MapWhile<Self, B, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> Option<B>,
+    {
+        MapWhile {
+            it: self,
+            f,
+            item: None,
+            done: false,
         }
-        self.next()
     }
 
-    /// Creates a normal, non-streaming, iterator with elements produced by calling `to_owned` on
-    /// the elements of this iterator.
+    /// Returns a clone of the maximum element of the iterator.
     ///
-    /// Requires the `alloc` feature.
-    #[cfg(feature = "alloc")]
+    /// The element is cloned since a reference to it would be invalidated by advancing the
+    /// iterator. If several elements are equally maximum, the last one is returned.
     #[inline]
-    fn owned(self) -> Owned<Self>
+    fn max(self) -> Option<Self::Item>
     where
         Self: Sized,
-        Self::Item: ToOwned,
+        Self::Item: Ord + Clone,
     {
-        Owned(self)
+        self.fold(None, |max: Option<Self::Item>, item| match max {
+            Some(max) if *item < max => Some(max),
+            _ => Some(item.clone()),
+        })
     }
 
-    /// Returns the index of the first element of the iterator matching a predicate.
+    /// Returns a clone of the maximum element of the iterator with respect to the specified
+    /// comparison function.
+    ///
+    /// If several elements are equally maximum, the last one is returned.
     #[inline]
-    fn position<F>(&mut self, mut f: F) -> Option<usize>
+    fn max_by<F>(self, mut compare: F) -> Option<Self::Item>
     where
         Self: Sized,
-        F: FnMut(&Self::Item) -> bool,
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, &Self::Item) -> cmp::Ordering,
     {
-        let mut n = 0;
-
-        while let Some(i) = self.next() {
-            if f(i) {
-                return Some(n);
-            }
-            n += 1;
-        }
-
-        None
+        self.fold(None, |max: Option<Self::Item>, item| match max {
+            Some(max) if compare(item, &max) == cmp::Ordering::Less => Some(max),
+            _ => Some(item.clone()),
+        })
     }
 
-    /// Creates an iterator which skips the first `n` elements.
+    /// Returns a clone of the element which gives the maximum value from the specified function,
+    /// together with that value.
+    ///
+    /// If several elements are equally maximum, the last one is returned.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let words = ["apple", "fig", "banana", "cherry"];
+    /// assert_eq!(convert(words).max_by_key_with(|w| w.len()), Some((6, "cherry")));
+    /// ```
     #[inline]
-    fn skip(self, n: usize) -> Skip<Self>
+    fn max_by_key_with<K, F>(self, mut f: F) -> Option<(K, Self::Item)>
     where
         Self: Sized,
+        Self::Item: Clone,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
     {
-        Skip { it: self, n }
+        self.fold(None, |best: Option<(K, Self::Item)>, item| {
+            let key = f(item);
+            match best {
+                Some((best_key, best)) if key < best_key => Some((best_key, best)),
+                _ => Some((key, item.clone())),
+            }
+        })
     }
 
-    /// Creates an iterator that skips initial elements matching a predicate.
-    #[inline]
-    fn skip_while<F>(self, f: F) -> SkipWhile<Self, F>
+    /// Creates an iterator which merges the elements of this iterator and another one, both
+    /// sorted in ascending order, into a single sorted sequence.
+    ///
+    /// When two elements are equal, the one from this iterator is yielded first.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut it = convert([1, 4]).merge(convert([2, 3]));
+    /// assert_eq!(it.next(), Some(&1));
+    /// assert_eq!(it.next(), Some(&2));
+    /// assert_eq!(it.next(), Some(&3));
+    /// assert_eq!(it.next(), Some(&4));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    #[inline]
+    fn merge<J>(self, other: J) -> Merge<Self, J>
     where
         Self: Sized,
-        F: FnMut(&Self::Item) -> bool,
+        J: StreamingIterator<Item = Self::Item>,
+        Self::Item: Ord,
     {
-        SkipWhile {
-            it: self,
-            f,
-            done: false,
+        Merge {
+            a: self,
+            b: other,
+            state: MergeState::Init,
         }
     }
 
-    /// Creates an iterator which only returns the first `n` elements.
+    /// Creates an iterator which merges the elements of this iterator and another one into a
+    /// single sequence, using a closure to decide which element comes first.
+    ///
+    /// The closure is called with the current elements of both iterators, and should return
+    /// `true` if the one from this iterator should be yielded first.
     #[inline]
-    fn take(self, n: usize) -> Take<Self>
+    fn merge_by<J, F>(self, other: J, first: F) -> MergeBy<Self, J, F>
     where
         Self: Sized,
+        J: StreamingIterator<Item = Self::Item>,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
     {
-        Take {
-            it: self,
-            n,
-            done: false,
+        MergeBy {
+            a: self,
+            b: other,
+            state: MergeState::Init,
+            first,
         }
     }
 
-    /// Creates an iterator which only returns initial elements matching a predicate.
+    /// Returns a clone of the minimum element of the iterator.
+    ///
+    /// The element is cloned since a reference to it would be invalidated by advancing the
+    /// iterator. If several elements are equally minimum, the first one is returned.
     #[inline]
-    fn take_while<F>(self, f: F) -> TakeWhile<Self, F>
+    fn min(self) -> Option<Self::Item>
     where
         Self: Sized,
-        F: FnMut(&Self::Item) -> bool,
+        Self::Item: Ord + Clone,
     {
-        TakeWhile {
-            it: self,
-            f,
-            done: false,
-        }
+        self.fold(None, |min: Option<Self::Item>, item| match min {
+            Some(min) if min <= *item => Some(min),
+            _ => Some(item.clone()),
+        })
     }
 
-    /// Creates an iterator which returns elemens in the opposite order.
+    /// Returns a clone of the minimum element of the iterator with respect to the specified
+    /// comparison function.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
     #[inline]
-    fn rev(self) -> Rev<Self>
+    fn min_by<F>(self, mut compare: F) -> Option<Self::Item>
     where
-        Self: Sized + DoubleEndedStreamingIterator,
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, &Self::Item) -> cmp::Ordering,
     {
-        Rev(self)
+        self.fold(None, |min: Option<Self::Item>, item| match min {
+            Some(min) if compare(&min, item) != cmp::Ordering::Greater => Some(min),
+            _ => Some(item.clone()),
+        })
     }
 
-    /// Reduces the iterator's elements to a single, final value.
+    /// Returns a clone of the element which gives the minimum value from the specified function,
+    /// together with that value.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let words = ["apple", "fig", "banana", "kiwi", "pea"];
+    /// assert_eq!(convert(words).min_by_key_with(|w| w.len()), Some((3, "fig")));
+    /// ```
     #[inline]
-    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    fn min_by_key_with<K, F>(self, mut f: F) -> Option<(K, Self::Item)>
     where
         Self: Sized,
-        F: FnMut(B, &Self::Item) -> B,
+        Self::Item: Clone,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
     {
-        let mut acc = init;
-        while let Some(item) = self.next() {
-            acc = f(acc, item);
-        }
-        acc
+        self.fold(None, |best: Option<(K, Self::Item)>, item| {
+            let key = f(item);
+            match best {
+                Some((best_key, best)) if best_key <= key => Some((best_key, best)),
+                _ => Some((key, item.clone())),
+            }
+        })
     }
 
-    /// Calls a closure on each element of an iterator.
+    /// Determines if the elements of this iterator are not equal to those of another.
     #[inline]
-    fn for_each<F>(self, mut f: F)
+    fn ne<J>(self, other: J) -> bool
     where
         Self: Sized,
-        F: FnMut(&Self::Item),
+        J: StreamingIterator,
+        Self::Item: PartialEq<J::Item>,
     {
-        self.fold((), move |(), item| f(item));
-    }
-}
-
-impl<'a, I: ?Sized> StreamingIterator for &'a mut I
-where
-    I: StreamingIterator,
-{
-    type Item = I::Item;
-
-    #[inline]
-    fn advance(&mut self) {
-        (**self).advance()
-    }
-
-    #[inline]
-    fn is_done(&self) -> bool {
-        (**self).is_done()
-    }
-
-    #[inline]
-    fn get(&self) -> Option<&Self::Item> {
-        (**self).get()
-    }
-
-    #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (**self).size_hint()
-    }
-
-    #[inline]
-    fn next(&mut self) -> Option<&Self::Item> {
-        (**self).next()
-    }
-}
-
-#[cfg(feature = "alloc")]
-impl<I: ?Sized> StreamingIterator for Box<I>
-where
-    I: StreamingIterator,
-{
-    type Item = I::Item;
-
-    #[inline]
-    fn advance(&mut self) {
-        (**self).advance()
-    }
-
-    #[inline]
-    fn is_done(&self) -> bool {
-        (**self).is_done()
+        !self.eq(other)
     }
 
+    /// Consumes the first `n` elements of the iterator, returning the next one.
     #[inline]
-    fn get(&self) -> Option<&Self::Item> {
-        (**self).get()
+    fn nth(&mut self, n: usize) -> Option<&Self::Item> {
+        if self.advance_by(n).is_err() {
+            return None;
+        }
+        self.next()
     }
 
+    /// Consumes the first `n` elements of the iterator, returning a clone of the next one.
+    ///
+    /// Unlike `nth`, the returned value does not borrow the iterator, so iteration can continue
+    /// while it is still in use.
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (**self).size_hint()
+    fn nth_owned(&mut self, n: usize) -> Option<Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        self.nth(n).cloned()
     }
 
+    /// Creates a normal, non-streaming, iterator with elements produced by calling `to_owned` on
+    /// the elements of this iterator.
+    ///
+    /// Requires the `alloc` feature.
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn next(&mut self) -> Option<&Self::Item> {
-        (**self).next()
+    fn owned(self) -> Owned<Self>
+    where
+        Self: Sized,
+        Self::Item: ToOwned,
+    {
+        Owned(self)
     }
-}
-
-/// A streaming iterator able to yield elements from both ends.
-pub trait DoubleEndedStreamingIterator: StreamingIterator {
-    /// Advances the iterator to the next element from the back of the iterator.
-    ///
-    /// Double ended iterators just after the last element, so this should be called before `get`
-    /// when iterating in reverse.
-    ///
-    /// The behavior of calling this method after the iterator has been exhausted is unspecified.
-    fn advance_back(&mut self);
 
-    /// Advances the iterator and returns the next value from the back.
+    /// Creates an iterator which combines each element with the preceding one, yielding the
+    /// results.
     ///
-    /// The behavior of calling this method after the iterator has been exhausted is unspecified.
+    /// A clone of the preceding element is kept, so the first element only ever appears as the
+    /// first argument of the closure, and an iterator with fewer than two elements yields nothing.
     ///
-    /// The default implementation simply calls `advance_back` followed by `get`.
-    #[inline]
-    fn next_back(&mut self) -> Option<&Self::Item> {
-        self.advance_back();
-        (*self).get()
-    }
-
-    /// Reduces the iterator's elements to a single, final value, starting from the back.
-    #[inline]
-    fn rfold<B, F>(mut self, init: B, mut f: F) -> B
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut deltas = convert([1, 4, 9]).pairwise(|prev, cur| cur - prev);
+    /// assert_eq!(deltas.next(), Some(&3));
+    /// assert_eq!(deltas.next(), Some(&5));
+    /// assert_eq!(deltas.next(), None);
+    /// ```
+    #[inline]
+    fn pairwise<B, F>(self, f: F) -> Pairwise<Self, B, F>
     where
         Self: Sized,
-        F: FnMut(B, &Self::Item) -> B,
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, &Self::Item) -> B,
     {
-        let mut acc = init;
-        while let Some(item) = self.next_back() {
-            acc = f(acc, item);
+        Pairwise {
+            it: self,
+            f,
+            prev: None,
+            item: None,
         }
-        acc
     }
-}
-
-/// An interface for dealing with mutable streaming iterators.
-pub trait StreamingIteratorMut: StreamingIterator {
-    /// Returns a mutable reference to the current element of the iterator.
-    ///
-    /// The behavior of calling this method before `advance` has been called is unspecified.
-    ///
-    /// Modifications through this reference may also have an unspecified effect on further
-    /// iterator advancement, but implementations are encouraged to document this.
-    fn get_mut(&mut self) -> Option<&mut Self::Item>;
 
-    /// Advances the iterator and returns the next mutable value.
-    ///
-    /// The behavior of calling this method after the end of the iterator has been reached is
-    /// unspecified.
+    /// Lexicographically compares the elements of this iterator with those of another, if they are
+    /// comparable.
     ///
-    /// The default implementation simply calls `advance` followed by `get_mut`.
+    /// If one iterator is a prefix of the other, the shorter one compares as less. `None` is
+    /// returned as soon as a pair of elements is incomparable.
     #[inline]
-    fn next_mut(&mut self) -> Option<&mut Self::Item> {
-        self.advance();
-        (*self).get_mut()
+    fn partial_cmp<J>(mut self, mut other: J) -> Option<cmp::Ordering>
+    where
+        Self: Sized,
+        J: StreamingIterator,
+        Self::Item: PartialOrd<J::Item>,
+    {
+        loop {
+            match (self.next(), other.next()) {
+                (Some(a), Some(b)) => match a.partial_cmp(b) {
+                    Some(cmp::Ordering::Equal) => {}
+                    ordering => return ordering,
+                },
+                (Some(_), None) => return Some(cmp::Ordering::Greater),
+                (None, Some(_)) => return Some(cmp::Ordering::Less),
+                (None, None) => return Some(cmp::Ordering::Equal),
+            }
+        }
     }
 
-    /// Reduces the iterator's mutable elements to a single, final value.
+    /// Determines if the elements of this iterator are lexicographically less than those of
+    /// another.
     #[inline]
-    fn fold_mut<B, F>(mut self, init: B, mut f: F) -> B
+    fn lt<J>(self, other: J) -> bool
     where
         Self: Sized,
-        F: FnMut(B, &mut Self::Item) -> B,
+        J: StreamingIterator,
+        Self::Item: PartialOrd<J::Item>,
     {
-        let mut acc = init;
-        while let Some(item) = self.next_mut() {
-            acc = f(acc, item);
-        }
-        acc
+        matches!(self.partial_cmp(other), Some(cmp::Ordering::Less))
     }
 
-    /// Calls a closure on each mutable element of an iterator.
+    /// Determines if the elements of this iterator are lexicographically less than or equal to
+    /// those of another.
     #[inline]
-    fn for_each_mut<F>(self, mut f: F)
+    fn le<J>(self, other: J) -> bool
     where
         Self: Sized,
-        F: FnMut(&mut Self::Item),
+        J: StreamingIterator,
+        Self::Item: PartialOrd<J::Item>,
     {
-        self.fold_mut((), move |(), item| f(item));
+        matches!(
+            self.partial_cmp(other),
+            Some(cmp::Ordering::Less | cmp::Ordering::Equal)
+        )
     }
 
-    /// Creates a regular, non-streaming iterator which transforms mutable elements
-    /// of this iterator by passing them to a closure.
+    /// Determines if the elements of this iterator are lexicographically greater than those of
+    /// another.
     #[inline]
-    fn map_deref_mut<B, F>(self, f: F) -> MapDerefMut<Self, F>
+    fn gt<J>(self, other: J) -> bool
     where
         Self: Sized,
-        F: FnMut(&mut Self::Item) -> B,
+        J: StreamingIterator,
+        Self::Item: PartialOrd<J::Item>,
     {
-        MapDerefMut { it: self, f }
+        matches!(self.partial_cmp(other), Some(cmp::Ordering::Greater))
     }
 
-    /// Creates an iterator which flattens nested streaming iterators.
+    /// Determines if the elements of this iterator are lexicographically greater than or equal to
+    /// those of another.
     #[inline]
-    fn flatten(self) -> Flatten<Self>
+    fn ge<J>(self, other: J) -> bool
     where
         Self: Sized,
-        Self::Item: StreamingIterator,
+        J: StreamingIterator,
+        Self::Item: PartialOrd<J::Item>,
     {
-        Flatten {
-            iter: self,
-            first: true,
-        }
-    }
-}
-
-impl<'a, I: ?Sized> StreamingIteratorMut for &'a mut I
-where
-    I: StreamingIteratorMut,
-{
-    #[inline]
-    fn get_mut(&mut self) -> Option<&mut Self::Item> {
-        (**self).get_mut()
-    }
-
-    #[inline]
-    fn next_mut(&mut self) -> Option<&mut Self::Item> {
-        (**self).next_mut()
-    }
-}
-
-#[cfg(feature = "alloc")]
-impl<I: ?Sized> StreamingIteratorMut for Box<I>
-where
-    I: StreamingIteratorMut,
-{
-    #[inline]
-    fn get_mut(&mut self) -> Option<&mut Self::Item> {
-        (**self).get_mut()
-    }
-
-    #[inline]
-    fn next_mut(&mut self) -> Option<&mut Self::Item> {
-        (**self).next_mut()
+        matches!(
+            self.partial_cmp(other),
+            Some(cmp::Ordering::Greater | cmp::Ordering::Equal)
+        )
     }
-}
 
-/// A mutable streaming iterator able to yield elements from both ends.
-pub trait DoubleEndedStreamingIteratorMut:
-    DoubleEndedStreamingIterator + StreamingIteratorMut
-{
-    /// Advances the iterator and returns the next mutable value from the back.
+    /// Consumes the iterator, collecting owned copies of its elements into two collections: those
+    /// for which the predicate returns `true`, and those for which it returns `false`.
     ///
-    /// The behavior of calling this method after the end of the iterator has been reached is
-    /// unspecified.
+    /// Requires the `alloc` feature.
     ///
-    /// The default implementation simply calls `advance_back` followed by `get_mut`.
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let (even, odd): (Vec<_>, Vec<_>) = convert([1, 2, 3, 4]).partition_deref(|&i| i % 2 == 0);
+    /// assert_eq!(even, [2, 4]);
+    /// assert_eq!(odd, [1, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn next_back_mut(&mut self) -> Option<&mut Self::Item> {
-        self.advance_back();
-        (*self).get_mut()
+    fn partition_deref<C, F>(self, mut f: F) -> (C, C)
+    where
+        Self: Sized,
+        Self::Item: ToOwned,
+        F: FnMut(&Self::Item) -> bool,
+        C: Default + Extend<<Self::Item as ToOwned>::Owned>,
+    {
+        self.fold((C::default(), C::default()), |(mut yes, mut no), item| {
+            if f(item) {
+                yes.extend(Some(item.to_owned()));
+            } else {
+                no.extend(Some(item.to_owned()));
+            }
+            (yes, no)
+        })
     }
 
-    /// Reduces the iterator's mutable elements to a single, final value, starting from the back.
-    #[inline]
-    fn rfold_mut<B, F>(mut self, init: B, mut f: F) -> B
+    /// Creates an iterator which can look at the next element without consuming it.
+    ///
+    /// Peeking has to advance the underlying iterator, which replaces its current element, so
+    /// while a peek is outstanding, `get` returns `None`. The next call to `advance` then moves
+    /// to the peeked element without advancing the underlying iterator again.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut it = convert([1, 2]).peekable();
+    /// assert_eq!(it.peek(), Some(&1));
+    /// assert_eq!(it.next(), Some(&1));
+    /// assert_eq!(it.peek(), Some(&2));
+    /// assert_eq!(it.peek(), Some(&2));
+    /// assert_eq!(it.next(), Some(&2));
+    /// assert_eq!(it.peek(), None);
+    /// ```
+    #[inline]
+    fn peekable(self) -> Peekable<Self>
     where
         Self: Sized,
-        F: FnMut(B, &mut Self::Item) -> B,
     {
-        let mut acc = init;
-        while let Some(item) = self.next_back_mut() {
-            acc = f(acc, item);
+        Peekable {
+            it: self,
+            peeked: false,
         }
-        acc
     }
-}
-// Note, in theory we could blanket-impl `DoubleEndedStreamingIteratorMut`, but that
-// wouldn't allow custom folding until we can do it with Rust specialization.
-
-/// A streaming iterator that concatenates two streaming iterators
-#[derive(Debug)]
-pub struct Chain<A, B> {
-    a: A,
-    b: B,
-    state: ChainState,
-}
-
-#[derive(Debug)]
-enum ChainState {
-    // Both iterators have items remaining and we are iterating forward
-    BothForward,
-    // Both iterators have items remaining and we are iterating backward
-    BothBackward,
-    // Only the front iterator has items
-    Front,
-    // Only the back iterator has items
-    Back,
-}
-
-impl<A, B> StreamingIterator for Chain<A, B>
-where
-    A: StreamingIterator,
-    B: StreamingIterator<Item = A::Item>,
-{
-    type Item = A::Item;
 
+    /// Returns the index of the first element of the iterator matching a predicate.
     #[inline]
-    fn advance(&mut self) {
-        use crate::ChainState::*;
+    fn position<F>(&mut self, mut f: F) -> Option<usize>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        let mut n = 0;
 
-        match self.state {
-            BothForward | BothBackward => {
-                self.a.advance();
-                self.state = if self.a.is_done() {
-                    self.b.advance();
-                    Back
-                } else {
-                    BothForward
-                };
+        while let Some(i) = self.next() {
+            if f(i) {
+                return Some(n);
             }
-            Front => self.a.advance(),
-            Back => self.b.advance(),
+            n += 1;
         }
-    }
 
-    #[inline]
-    fn is_done(&self) -> bool {
-        use crate::ChainState::*;
+        None
+    }
 
-        match self.state {
-            BothForward | Front => self.a.is_done(),
-            BothBackward | Back => self.b.is_done(),
+    /// Creates an iterator which reports its progress to a closure.
+    ///
+    /// Each time an element is yielded, the closure is called with the number of elements yielded
+    /// so far and the upper bound of the number of elements remaining, as given by `size_hint`.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut it = convert(['a', 'b']).progress(|done, remaining| {
+    ///     println!("{} done, {:?} remaining", done, remaining);
+    /// });
+    /// while let Some(_) = it.next() {}
+    /// ```
+    #[inline]
+    fn progress<F>(self, f: F) -> Progress<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(usize, Option<usize>),
+    {
+        Progress {
+            it: self,
+            f,
+            count: 0,
         }
     }
 
+    /// Reduces the iterator's elements to a single value by repeatedly applying a closure.
+    ///
+    /// Unlike `fold`, there is no initial value: the accumulator is seeded with a clone of the
+    /// first element, which is why `Clone` is required, and the closure then combines it with
+    /// each subsequent element. Returns `None` if the iterator is empty.
     #[inline]
-    fn get(&self) -> Option<&Self::Item> {
-        use crate::ChainState::*;
-
-        match self.state {
-            BothForward | Front => self.a.get(),
-            BothBackward | Back => self.b.get(),
-        }
+    fn reduce<F>(mut self, f: F) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(Self::Item, &Self::Item) -> Self::Item,
+    {
+        let first = self.next()?.clone();
+        Some(self.fold(first, f))
     }
 
-    #[inline]
-    fn fold<Acc, F>(self, init: Acc, mut f: F) -> Acc
+    /// Creates an iterator which transforms elements with a closure that also updates a piece of
+    /// state, stopping as soon as the closure returns `None`.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut it = convert([1, 2, 3]).scan(0, |sum, &i| {
+    ///     *sum += i;
+    ///     Some(*sum)
+    /// });
+    /// assert_eq!(it.next(), Some(&1));
+    /// assert_eq!(it.next(), Some(&3));
+    /// assert_eq!(it.next(), Some(&6));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    #[inline]
+    fn scan<St, B, F>(self, initial_state: St, f: F) -> Scan<Self, St, B, F>
     where
         Self: Sized,
-        F: FnMut(Acc, &Self::Item) -> Acc,
+        F: FnMut(&mut St, &Self::Item) -> Option<B>,
     {
-        let mut accum = init;
-        match self.state {
-            ChainState::Back => {}
-            _ => accum = self.a.fold(accum, &mut f),
-        }
-        match self.state {
-            ChainState::Front => {}
-            _ => accum = self.b.fold(accum, &mut f),
+        Scan {
+            it: self,
+            f,
+            state: initial_state,
+            item: None,
+            done: false,
         }
-        accum
     }
-}
-
-impl<A, B> DoubleEndedStreamingIterator for Chain<A, B>
-where
-    A: DoubleEndedStreamingIterator,
-    B: DoubleEndedStreamingIterator<Item = A::Item>,
-{
-    #[inline]
-    fn advance_back(&mut self) {
-        use crate::ChainState::*;
 
-        match self.state {
-            BothForward | BothBackward => {
-                self.b.advance_back();
-                self.state = if self.b.is_done() {
-                    self.a.advance_back();
-                    Front
-                } else {
-                    BothBackward
-                };
-            }
-            Front => self.a.advance_back(),
-            Back => self.b.advance_back(),
+    /// Creates an iterator which transforms elements with a closure that also updates a piece of
+    /// state, which can be retrieved with `ScanState::into_state` once iteration is complete.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut it = convert([1, 2, 3]).scan_with_state(0, |sum, &i| {
+    ///     *sum += i;
+    ///     i * 10
+    /// });
+    /// assert_eq!(it.next(), Some(&10));
+    /// assert_eq!(it.next(), Some(&20));
+    /// assert_eq!(it.next(), Some(&30));
+    /// assert_eq!(it.next(), None);
+    /// assert_eq!(it.into_state(), 6);
+    /// ```
+    #[inline]
+    fn scan_with_state<St, B, F>(self, init: St, f: F) -> ScanState<Self, St, B, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut St, &Self::Item) -> B,
+    {
+        ScanState {
+            scan: Scan {
+                it: self,
+                f,
+                state: init,
+                item: None,
+                done: false,
+            },
         }
     }
 
+    /// Creates an iterator which skips the first `n` elements.
     #[inline]
-    fn rfold<Acc, F>(self, init: Acc, mut f: F) -> Acc
+    fn skip(self, n: usize) -> Skip<Self>
     where
         Self: Sized,
-        F: FnMut(Acc, &Self::Item) -> Acc,
     {
-        let mut accum = init;
-        match self.state {
-            ChainState::Front => {}
-            _ => accum = self.b.rfold(accum, &mut f),
-        }
-        match self.state {
-            ChainState::Back => {}
-            _ => accum = self.a.rfold(accum, &mut f),
-        }
-        accum
+        Skip { it: self, n }
     }
-}
 
-impl<A, B> StreamingIteratorMut for Chain<A, B>
-where
-    A: StreamingIteratorMut,
-    B: StreamingIteratorMut<Item = A::Item>,
-{
+    /// Advances the iterator to the first element for which `pred` returns `false`, returning it.
+    ///
+    /// The elements are expected to be partitioned by `pred`, with every element for which it
+    /// returns `true` coming before every element for which it returns `false`. This
+    /// implementation scans linearly, but iterators over slices created by `convert_ref` provide
+    /// an inherent `skip_to` which uses a binary search instead and relies on that requirement.
     #[inline]
-    fn get_mut(&mut self) -> Option<&mut Self::Item> {
-        use crate::ChainState::*;
-
-        match self.state {
-            BothForward | Front => self.a.get_mut(),
-            BothBackward | Back => self.b.get_mut(),
-        }
+    fn skip_to<F>(&mut self, mut pred: F) -> Option<&Self::Item>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.find(|i| !pred(i))
     }
 
+    /// Creates an iterator that skips initial elements matching a predicate.
     #[inline]
-    fn fold_mut<Acc, F>(self, init: Acc, mut f: F) -> Acc
+    fn skip_while<F>(self, f: F) -> SkipWhile<Self, F>
     where
         Self: Sized,
-        F: FnMut(Acc, &mut Self::Item) -> Acc,
+        F: FnMut(&Self::Item) -> bool,
     {
-        let mut accum = init;
-        match self.state {
-            ChainState::Back => {}
-            _ => accum = self.a.fold_mut(accum, &mut f),
-        }
-        match self.state {
-            ChainState::Front => {}
-            _ => accum = self.b.fold_mut(accum, &mut f),
+        SkipWhile {
+            it: self,
+            f,
+            done: false,
         }
-        accum
     }
-}
 
-impl<A, B> DoubleEndedStreamingIteratorMut for Chain<A, B>
-where
-    A: DoubleEndedStreamingIteratorMut,
-    B: DoubleEndedStreamingIteratorMut<Item = A::Item>,
-{
-    fn rfold_mut<Acc, F>(self, init: Acc, mut f: F) -> Acc
+    /// Advances the iterator past the first element matching a predicate, returning whether one
+    /// was found.
+    ///
+    /// The matching element is consumed, so continuing to iterate yields the elements after it.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert_ref, StreamingIterator};
+    /// let mut lines = convert_ref(["Host: example.com", "", "body"]);
+    /// assert!(lines.split_once(|line| line.is_empty()));
+    /// assert_eq!(lines.next(), Some("body"));
+    /// ```
+    #[inline]
+    fn split_once<F>(&mut self, f: F) -> bool
     where
         Self: Sized,
-        F: FnMut(Acc, &mut Self::Item) -> Acc,
+        F: FnMut(&Self::Item) -> bool,
     {
-        let mut accum = init;
-        match self.state {
-            ChainState::Front => {}
-            _ => accum = self.b.rfold_mut(accum, &mut f),
-        }
-        match self.state {
-            ChainState::Back => {}
-            _ => accum = self.a.rfold_mut(accum, &mut f),
-        }
-        accum
-    }
-}
-
-/// A normal, non-streaming, iterator which converts the elements of a streaming iterator into owned
-/// values by cloning them.
-#[derive(Clone, Debug)]
-pub struct Cloned<I>(I);
-
-impl<I> Iterator for Cloned<I>
-where
-    I: StreamingIterator,
-    I::Item: Clone,
-{
-    type Item = I::Item;
-
-    #[inline]
-    fn next(&mut self) -> Option<I::Item> {
-        self.0.next().cloned()
+        self.find(f).is_some()
     }
 
-    #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
+    /// Creates an iterator which yields the first element and then every `step`th element after
+    /// it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is 0.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert_mut, StreamingIterator, StreamingIteratorMut};
+    /// let mut samples = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// convert_mut(&mut samples).step_by(4).for_each_mut(|s| *s = 0);
+    /// assert_eq!(samples, [0, 2, 3, 4, 0, 6, 7, 8, 0]);
+    /// ```
+    #[inline]
+    fn step_by(self, step: usize) -> StepBy<Self>
+    where
+        Self: Sized,
+    {
+        assert!(step != 0, "step must be non-zero");
+        StepBy {
+            it: self,
+            step: step - 1,
+            first: true,
+        }
     }
 
+    /// Sums the values obtained by applying a closure to each element.
+    ///
+    /// Since elements are only available by reference, they cannot be summed directly through
+    /// `core::iter::Sum` as with `Iterator::sum`. Instead, the closure projects each element to
+    /// an owned value, and the values are added together starting from `S::default()`.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let words = ["one", "three", "five"];
+    /// assert_eq!(convert(words).sum_by(|w| w.len()), 12);
+    /// ```
     #[inline]
-    fn fold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
+    fn sum_by<S, F>(self, mut f: F) -> S
     where
         Self: Sized,
-        Fold: FnMut(Acc, Self::Item) -> Acc,
+        S: Add<Output = S> + Default,
+        F: FnMut(&Self::Item) -> S,
     {
-        self.0.fold(init, move |acc, item| f(acc, item.clone()))
+        self.fold(S::default(), |sum, item| sum + f(item))
     }
-}
 
-impl<I> DoubleEndedIterator for Cloned<I>
-where
-    I: DoubleEndedStreamingIterator,
-    I::Item: Clone,
-{
+    /// Creates an iterator which only returns the first `n` elements.
     #[inline]
-    fn next_back(&mut self) -> Option<I::Item> {
-        self.0.next_back().cloned()
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take {
+            it: self,
+            n,
+            done: false,
+        }
     }
 
+    /// Creates an iterator which only returns initial elements matching a predicate.
     #[inline]
-    fn rfold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
+    fn take_while<F>(self, f: F) -> TakeWhile<Self, F>
     where
         Self: Sized,
-        Fold: FnMut(Acc, Self::Item) -> Acc,
+        F: FnMut(&Self::Item) -> bool,
     {
-        self.0.rfold(init, move |acc, item| f(acc, item.clone()))
+        TakeWhile {
+            it: self,
+            f,
+            done: false,
+        }
     }
-}
-
-/// A normal, non-streaming, iterator which converts the elements of a streaming iterator into owned
-/// values by copying them.
-#[derive(Clone, Debug)]
-pub struct Copied<I>(I);
-
-impl<I> Iterator for Copied<I>
-where
-    I: StreamingIterator,
-    I::Item: Copy,
-{
-    type Item = I::Item;
 
+    /// Creates an iterator which yields the elements of a row-major matrix with `cols` columns in
+    /// column-major order, i.e. the elements of its transpose.
+    ///
+    /// The entire matrix is buffered: on the first call to `advance` or `advance_back`, every
+    /// element of this iterator is cloned into an internal `Vec`. If the number of elements is not
+    /// a multiple of `cols`, the trailing incomplete row is discarded.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cols` is 0.
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn next(&mut self) -> Option<I::Item> {
-        self.0.next().copied()
+    fn transpose(self, cols: usize) -> Transpose<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        assert!(cols != 0, "cols is zero");
+        Transpose {
+            it: Some(self),
+            buf: Vec::new(),
+            cols,
+            front: 0,
+            back: 0,
+            current: None,
+        }
     }
 
+    /// Creates an iterator which returns initial elements matching a predicate, followed by the
+    /// first element which does not match it.
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
+    fn take_while_inclusive<F>(self, f: F) -> TakeWhileInclusive<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        TakeWhileInclusive {
+            it: self,
+            f,
+            done: false,
+            exhausted: false,
+        }
     }
 
+    /// Collects owned copies of the elements of the iterator into a `Vec`.
+    ///
+    /// This is a shorthand for `collect::<Vec<_>>()`.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// assert_eq!(convert([1, 2, 3]).to_vec(), vec![1, 2, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn fold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
+    fn to_vec(self) -> Vec<<Self::Item as ToOwned>::Owned>
     where
         Self: Sized,
-        Fold: FnMut(Acc, Self::Item) -> Acc,
+        Self::Item: ToOwned,
     {
-        self.0.fold(init, move |acc, &item| f(acc, item))
+        self.collect()
     }
-}
 
-impl<I> DoubleEndedIterator for Copied<I>
-where
-    I: DoubleEndedStreamingIterator,
-    I::Item: Copy,
-{
+    /// Consumes the iterator, splitting each element into two projections which are collected
+    /// into separate collections.
+    ///
+    /// Only the projections are produced, so the elements themselves never need to be cloned.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let (ids, names): (Vec<_>, Vec<_>) =
+    ///     convert([(1, "a"), (2, "b")]).unzip_deref(|&(id, _)| id, |&(_, name)| name);
+    /// assert_eq!(ids, [1, 2]);
+    /// assert_eq!(names, ["a", "b"]);
+    /// ```
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn next_back(&mut self) -> Option<I::Item> {
-        self.0.next_back().copied()
+    fn unzip_deref<A, B, FA, FB, CA, CB>(self, mut fa: FA, mut fb: FB) -> (CA, CB)
+    where
+        Self: Sized,
+        FA: FnMut(&Self::Item) -> A,
+        FB: FnMut(&Self::Item) -> B,
+        CA: Default + Extend<A>,
+        CB: Default + Extend<B>,
+    {
+        self.fold((CA::default(), CB::default()), |(mut ca, mut cb), item| {
+            ca.extend(Some(fa(item)));
+            cb.extend(Some(fb(item)));
+            (ca, cb)
+        })
     }
 
-    #[inline]
-    fn rfold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
+    /// Creates an iterator which yields each element together with a flag which is `true` only for
+    /// the last element.
+    ///
+    /// To know whether an element is the last one, the iterator has to advance the underlying
+    /// iterator past it, so each element is cloned out of the underlying iterator before it is
+    /// yielded.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut out = String::new();
+    /// let mut it = convert(["a", "b", "c"]).with_last();
+    /// while let Some(&(s, last)) = it.next() {
+    ///     out.push_str(s);
+    ///     out.push_str(if last { "." } else { ", " });
+    /// }
+    /// assert_eq!(out, "a, b, c.");
+    /// ```
+    #[inline]
+    fn with_last(self) -> WithLast<Self>
     where
         Self: Sized,
-        Fold: FnMut(Acc, Self::Item) -> Acc,
+        Self::Item: Clone,
     {
-        self.0.rfold(init, move |acc, &item| f(acc, item))
+        WithLast {
+            it: self,
+            item: None,
+            first: true,
+        }
     }
-}
-
-/// A streaming iterator which filters the elements of a streaming iterator with a predicate.
-#[derive(Debug)]
-pub struct Filter<I, F> {
-    it: I,
-    f: F,
-}
-
-impl<I, F> StreamingIterator for Filter<I, F>
-where
-    I: StreamingIterator,
-    F: FnMut(&I::Item) -> bool,
-{
-    type Item = I::Item;
 
-    #[inline]
-    fn advance(&mut self) {
-        while let Some(i) = self.it.next() {
-            if (self.f)(i) {
-                break;
-            }
+    /// Creates an iterator which pairs each element with a successive slot of a mutable slice.
+    ///
+    /// The output slice is consumed positionally: the `n`th element of this iterator is paired
+    /// with `out[n]`. Iteration stops when either this iterator or the slice is exhausted. The
+    /// slot paired with the current element is available through `ZipWrite::get_mut`, and both
+    /// halves at once through `ZipWrite::get_pair`.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut out = [0; 3];
+    /// let mut it = convert([1, 2, 3]).zip_write(&mut out);
+    /// while let Some((&i, slot)) = it.next_pair() {
+    ///     *slot = i * 10;
+    /// }
+    /// assert_eq!(out, [10, 20, 30]);
+    /// ```
+    #[inline]
+    fn zip_write<T>(self, out: &mut [T]) -> ZipWrite<'_, Self, T>
+    where
+        Self: Sized,
+    {
+        ZipWrite {
+            it: self,
+            out,
+            pos: 0,
+            done: false,
         }
     }
 
-    #[inline]
-    fn is_done(&self) -> bool {
-        self.it.is_done()
+    /// Creates an iterator which combines the elements of this iterator and another one with a
+    /// closure, yielding the results.
+    ///
+    /// Both iterators are advanced together, and iteration stops as soon as either of them is
+    /// exhausted.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut it = convert([1, 2, 3]).zip_map(convert([10, 20]), |&a, &b| a + b);
+    /// assert_eq!(it.next(), Some(&11));
+    /// assert_eq!(it.next(), Some(&22));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    #[inline]
+    fn zip_map<J, B, F>(self, other: J, f: F) -> ZipMap<Self, J, B, F>
+    where
+        Self: Sized,
+        J: StreamingIterator,
+        F: FnMut(&Self::Item, &J::Item) -> B,
+    {
+        ZipMap {
+            a: self,
+            b: other,
+            f,
+            item: None,
+        }
     }
 
-    #[inline]
-    fn get(&self) -> Option<&I::Item> {
-        self.it.get()
+    /// Creates an iterator which combines the elements of this iterator and another one with a
+    /// closure, yielding the results, until both iterators are exhausted.
+    ///
+    /// Once one of the iterators runs out, the closure is passed `None` in its place for the
+    /// remaining elements of the other.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut it = convert([1, 2]).zip_longest_map(convert([10]), |a, b| {
+    ///     a.copied().unwrap_or(0) + b.copied().unwrap_or(0)
+    /// });
+    /// assert_eq!(it.next(), Some(&11));
+    /// assert_eq!(it.next(), Some(&2));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    #[inline]
+    fn zip_longest_map<J, B, F>(self, other: J, f: F) -> ZipLongestMap<Self, J, B, F>
+    where
+        Self: Sized,
+        J: StreamingIterator,
+        F: FnMut(Option<&Self::Item>, Option<&J::Item>) -> B,
+    {
+        ZipLongestMap {
+            a: self,
+            b: other,
+            a_done: false,
+            b_done: false,
+            f,
+            item: None,
+        }
     }
 
+    /// Creates an iterator which returns elemens in the opposite order.
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, self.it.size_hint().1)
+    fn rev(self) -> Rev<Self>
+    where
+        Self: Sized + DoubleEndedStreamingIterator,
+    {
+        Rev(self)
     }
 
+    /// Creates an iterator which returns owned copies of the elements in the opposite order,
+    /// without requiring `DoubleEndedStreamingIterator`.
+    ///
+    /// All of the elements are collected into a buffer on the first call to `advance` or
+    /// `advance_back`, so this takes O(n) memory and the iterator must be finite. Prefer `rev` for
+    /// iterators which are double-ended.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// ```
+    /// # use streaming_iterator::{from_fn, StreamingIterator};
+    /// let mut n = 0;
+    /// let mut it = from_fn(|| {
+    ///     n += 1;
+    ///     if n <= 3 { Some(n) } else { None }
+    /// })
+    /// .rev_buffered();
+    /// assert_eq!(it.next(), Some(&3));
+    /// assert_eq!(it.next(), Some(&2));
+    /// assert_eq!(it.next(), Some(&1));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    fn rev_buffered(self) -> RevBuffered<Self>
     where
         Self: Sized,
-        Fold: FnMut(Acc, &Self::Item) -> Acc,
+        Self::Item: ToOwned,
     {
-        let mut f = self.f;
-        self.it.fold(
-            init,
-            move |acc, item| {
-                if f(item) {
-                    fold(acc, item)
-                } else {
-                    acc
-                }
-            },
-        )
-    }
-}
-
-impl<I, F> DoubleEndedStreamingIterator for Filter<I, F>
-where
-    I: DoubleEndedStreamingIterator,
-    F: FnMut(&I::Item) -> bool,
-{
-    #[inline]
-    fn advance_back(&mut self) {
-        while let Some(i) = self.it.next_back() {
-            if (self.f)(i) {
-                break;
-            }
+        RevBuffered {
+            it: self,
+            buf: Vec::new(),
+            filled: false,
+            front: 0,
+            back: 0,
+            current: None,
         }
     }
 
+    /// Reduces the iterator's elements to a single, final value.
     #[inline]
-    fn rfold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
     where
         Self: Sized,
-        Fold: FnMut(Acc, &Self::Item) -> Acc,
+        F: FnMut(B, &Self::Item) -> B,
     {
-        let mut f = self.f;
-        self.it.rfold(
-            init,
-            move |acc, item| {
-                if f(item) {
-                    fold(acc, item)
-                } else {
-                    acc
-                }
-            },
-        )
+        let mut acc = init;
+        while let Some(item) = self.next() {
+            acc = f(acc, item);
+        }
+        acc
     }
-}
 
-impl<I, F> StreamingIteratorMut for Filter<I, F>
-where
-    I: StreamingIteratorMut,
-    F: FnMut(&I::Item) -> bool,
-{
-    #[inline]
-    fn get_mut(&mut self) -> Option<&mut I::Item> {
-        self.it.get_mut()
+    /// Folds the iterator's remaining elements into a caller-owned accumulator in place.
+    ///
+    /// Unlike `fold`, the accumulator is never moved, which avoids shuffling large buffers in and
+    /// out of the closure.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert_ref, StreamingIterator};
+    /// let mut sentence = String::new();
+    /// convert_ref(["hello", " ", "world"]).fold_ref(&mut sentence, |s, word| s.push_str(word));
+    /// assert_eq!(sentence, "hello world");
+    /// ```
+    #[inline]
+    fn fold_ref<B: ?Sized, F>(&mut self, acc: &mut B, mut f: F)
+    where
+        Self: Sized,
+        F: FnMut(&mut B, &Self::Item),
+    {
+        while let Some(item) = self.next() {
+            f(acc, item);
+        }
     }
 
+    /// Calls a closure on each element of an iterator.
     #[inline]
-    fn fold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    fn for_each<F>(self, mut f: F)
     where
         Self: Sized,
-        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+        F: FnMut(&Self::Item),
     {
-        let mut f = self.f;
-        self.it.fold_mut(
-            init,
-            move |acc, item| {
-                if f(&*item) {
-                    fold(acc, item)
-                } else {
-                    acc
-                }
-            },
-        )
+        self.fold((), move |(), item| f(item));
     }
-}
 
-impl<I, F> DoubleEndedStreamingIteratorMut for Filter<I, F>
-where
-    I: DoubleEndedStreamingIteratorMut,
-    F: FnMut(&I::Item) -> bool,
-{
+    /// Calls a closure on each element of an iterator together with its index.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// convert(["a", "b"]).for_each_indexed(|i, line| println!("{}: {}", i, line));
+    /// ```
     #[inline]
-    fn rfold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    fn for_each_indexed<F>(self, mut f: F)
     where
         Self: Sized,
-        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+        F: FnMut(usize, &Self::Item),
     {
-        let mut f = self.f;
-        self.it.rfold_mut(
-            init,
-            move |acc, item| {
-                if f(&*item) {
-                    fold(acc, item)
-                } else {
-                    acc
-                }
-            },
-        )
+        self.fold(0, move |i, item| {
+            f(i, item);
+            i + 1
+        });
     }
-}
-
-/// An iterator which both filters and maps elements of a streaming iterator with a closure.
-#[derive(Debug)]
-pub struct FilterMap<I, B, F> {
-    it: I,
-    f: F,
-    item: Option<B>,
-}
-
-impl<I, B, F> StreamingIterator for FilterMap<I, B, F>
-where
-    I: StreamingIterator,
-    F: FnMut(&I::Item) -> Option<B>,
-{
-    type Item = B;
 
-    #[inline]
-    fn advance(&mut self) {
-        loop {
-            match self.it.next() {
-                Some(i) => {
-                    if let Some(i) = (self.f)(i) {
-                        self.item = Some(i);
-                        break;
-                    }
-                }
-                None => {
-                    self.item = None;
-                    break;
-                }
+    /// Reduces the iterator's elements to a single value, stopping as soon as the closure returns
+    /// `FoldWhile::Done`.
+    ///
+    /// If the fold is stopped early, the iterator is left positioned on the element which caused
+    /// it, and iteration can resume from the following element.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, FoldWhile, StreamingIterator};
+    /// let mut it = convert([1, 2, 3, 4]);
+    /// let sum = it.fold_while(0, |sum, &i| {
+    ///     if sum + i > 3 {
+    ///         FoldWhile::Done(sum)
+    ///     } else {
+    ///         FoldWhile::Continue(sum + i)
+    ///     }
+    /// });
+    /// assert_eq!(sum, 3);
+    /// assert_eq!(it.get(), Some(&3));
+    /// ```
+    #[inline]
+    fn fold_while<B, F>(&mut self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, &Self::Item) -> FoldWhile<B>,
+    {
+        let mut acc = init;
+        while let Some(item) = self.next() {
+            match f(acc, item) {
+                FoldWhile::Continue(next) => acc = next,
+                FoldWhile::Done(done) => return done,
             }
         }
+        acc
     }
 
-    #[inline]
-    fn get(&self) -> Option<&B> {
-        self.item.as_ref()
-    }
-
-    #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, self.it.size_hint().1)
-    }
-
-    #[inline]
-    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    /// Reduces the iterator's elements to a single value with a fallible closure, stopping at the
+    /// first error.
+    ///
+    /// If the closure returns an error, the iterator is left positioned on the element which
+    /// caused it, so that it can be inspected with `get`, and iteration can resume from the
+    /// following element.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut it = convert(["1", "2", "x", "4"]);
+    /// let sum = it.try_fold(0, |sum, s| s.parse::<i32>().map(|i| sum + i));
+    /// assert!(sum.is_err());
+    /// assert_eq!(it.get(), Some(&"x"));
+    /// ```
+    #[inline]
+    fn try_fold<B, E, F>(&mut self, init: B, mut f: F) -> Result<B, E>
     where
         Self: Sized,
-        Fold: FnMut(Acc, &Self::Item) -> Acc,
+        F: FnMut(B, &Self::Item) -> Result<B, E>,
     {
-        let mut f = self.f;
-        self.it.fold(init, move |acc, item| match f(item) {
-            Some(item) => fold(acc, &item),
-            None => acc,
-        })
-    }
-}
-
-impl<I, B, F> DoubleEndedStreamingIterator for FilterMap<I, B, F>
-where
-    I: DoubleEndedStreamingIterator,
-    F: FnMut(&I::Item) -> Option<B>,
-{
-    #[inline]
-    fn advance_back(&mut self) {
-        loop {
-            match self.it.next_back() {
-                Some(i) => {
-                    if let Some(i) = (self.f)(i) {
-                        self.item = Some(i);
-                        break;
-                    }
-                }
-                None => {
-                    self.item = None;
-                    break;
-                }
-            }
+        let mut acc = init;
+        while let Some(item) = self.next() {
+            acc = f(acc, item)?;
         }
+        Ok(acc)
     }
 
+    /// Calls a fallible closure on each element of an iterator, stopping at the first error.
+    ///
+    /// As with `try_fold`, the iterator is left positioned on the element which caused the error.
     #[inline]
-    fn rfold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    fn try_for_each<E, F>(&mut self, mut f: F) -> Result<(), E>
     where
         Self: Sized,
-        Fold: FnMut(Acc, &Self::Item) -> Acc,
+        F: FnMut(&Self::Item) -> Result<(), E>,
     {
-        let mut f = self.f;
-        self.it.rfold(init, move |acc, item| match f(item) {
-            Some(item) => fold(acc, &item),
-            None => acc,
-        })
+        self.try_fold((), move |(), item| f(item))
     }
 }
 
-impl<I, B, F> StreamingIteratorMut for FilterMap<I, B, F>
+impl<I: ?Sized> StreamingIterator for &mut I
 where
     I: StreamingIterator,
-    F: FnMut(&I::Item) -> Option<B>,
 {
+    type Item = I::Item;
+
     #[inline]
-    fn get_mut(&mut self) -> Option<&mut B> {
-        self.item.as_mut()
+    fn advance(&mut self) {
+        (**self).advance()
     }
 
     #[inline]
-    fn fold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
-    {
-        let mut f = self.f;
-        self.it.fold(init, move |acc, item| match f(item) {
-            Some(mut item) => fold(acc, &mut item),
-            None => acc,
-        })
+    fn is_done(&self) -> bool {
+        (**self).is_done()
     }
-}
 
-impl<I, B, F> DoubleEndedStreamingIteratorMut for FilterMap<I, B, F>
-where
-    I: DoubleEndedStreamingIterator,
-    F: FnMut(&I::Item) -> Option<B>,
-{
     #[inline]
-    fn rfold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
-    {
-        let mut f = self.f;
-        self.it.rfold(init, move |acc, item| match f(item) {
-            Some(mut item) => fold(acc, &mut item),
-            None => acc,
-        })
+    fn get(&self) -> Option<&Self::Item> {
+        (**self).get()
     }
-}
 
-/// A streaming iterator that maps elements to iterators with a closure and then yields the
-/// concatenation of the obtained iterators
-#[derive(Debug)]
-pub struct FlatMap<I, J, F> {
-    it: I,
-    f: F,
-    sub_iter: Option<J>,
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<&Self::Item> {
+        (**self).next()
+    }
 }
 
-impl<I, J, F> StreamingIterator for FlatMap<I, J, F>
+#[cfg(feature = "alloc")]
+impl<I: ?Sized> StreamingIterator for Box<I>
 where
     I: StreamingIterator,
-    F: FnMut(&I::Item) -> J,
-    J: StreamingIterator,
 {
-    type Item = J::Item;
+    type Item = I::Item;
 
     #[inline]
     fn advance(&mut self) {
-        loop {
-            if let Some(ref mut iter) = self.sub_iter {
-                iter.advance();
-                if !iter.is_done() {
-                    break;
-                }
-            }
-            if let Some(item) = self.it.next() {
-                self.sub_iter = Some((self.f)(item));
-            } else {
-                break;
-            }
-        }
+        (**self).advance()
     }
 
     #[inline]
     fn is_done(&self) -> bool {
-        match self.sub_iter {
-            Some(ref iter) => iter.is_done(),
-            None => true,
-        }
+        (**self).is_done()
     }
 
     #[inline]
     fn get(&self) -> Option<&Self::Item> {
-        self.sub_iter.as_ref().and_then(J::get)
+        (**self).get()
     }
 
     #[inline]
-    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, &Self::Item) -> Acc,
-    {
-        let mut acc = init;
-        if let Some(iter) = self.sub_iter {
-            acc = iter.fold(acc, &mut fold);
-        }
-        let mut f = self.f;
-        self.it.fold(acc, |acc, item| f(item).fold(acc, &mut fold))
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<&Self::Item> {
+        (**self).next()
     }
 }
 
-impl<I, J, F> StreamingIteratorMut for FlatMap<I, J, F>
-where
-    I: StreamingIterator,
-    F: FnMut(&I::Item) -> J,
-    J: StreamingIteratorMut,
-{
+/// A streaming iterator able to yield elements from both ends.
+pub trait DoubleEndedStreamingIterator: StreamingIterator {
+    /// Advances the iterator to the next element from the back of the iterator.
+    ///
+    /// Double ended iterators just after the last element, so this should be called before `get`
+    /// when iterating in reverse.
+    ///
+    /// The behavior of calling this method after the iterator has been exhausted is unspecified.
+    fn advance_back(&mut self);
+
+    /// Advances the iterator and returns the next value from the back.
+    ///
+    /// The behavior of calling this method after the iterator has been exhausted is unspecified.
+    ///
+    /// The default implementation simply calls `advance_back` followed by `get`.
     #[inline]
-    fn get_mut(&mut self) -> Option<&mut Self::Item> {
-        self.sub_iter.as_mut().and_then(J::get_mut)
+    fn next_back(&mut self) -> Option<&Self::Item> {
+        self.advance_back();
+        (*self).get()
     }
 
+    /// Advances the iterator from the back by `n` elements.
+    ///
+    /// On success, the iterator is left on the `n`th element from the back. If the iterator runs
+    /// out first, `Err(k)` is returned, where `k` is the number of advancements that could not be
+    /// made.
     #[inline]
-    fn fold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    fn advance_back_by(&mut self, n: usize) -> Result<(), usize> {
+        for i in 0..n {
+            self.advance_back();
+            if self.is_done() {
+                return Err(n - i);
+            }
+        }
+        Ok(())
+    }
+
+    /// Consumes the last `n` elements of the iterator, returning the next one from the back.
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<&Self::Item> {
+        if self.advance_back_by(n).is_err() {
+            return None;
+        }
+        self.next_back()
+    }
+
+    /// Reduces the iterator's elements to a single, final value, starting from the back.
+    #[inline]
+    fn rfold<B, F>(mut self, init: B, mut f: F) -> B
     where
         Self: Sized,
-        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+        F: FnMut(B, &Self::Item) -> B,
     {
         let mut acc = init;
-        if let Some(iter) = self.sub_iter {
-            acc = iter.fold_mut(acc, &mut fold);
+        while let Some(item) = self.next_back() {
+            acc = f(acc, item);
         }
-        let mut f = self.f;
-        self.it
-            .fold(acc, |acc, item| f(item).fold_mut(acc, &mut fold))
+        acc
     }
 }
 
-/// A streaming iterator that flattens nested streaming iterators.
-#[derive(Debug)]
-pub struct Flatten<I> {
-    iter: I,
-    first: bool,
-}
-
-impl<I> StreamingIterator for Flatten<I>
-where
-    I: StreamingIteratorMut,
-    I::Item: StreamingIterator,
-{
-    type Item = <I::Item as StreamingIterator>::Item;
+/// An interface for dealing with mutable streaming iterators.
+pub trait StreamingIteratorMut: StreamingIterator {
+    /// Returns a mutable reference to the current element of the iterator.
+    ///
+    /// The behavior of calling this method before `advance` has been called is unspecified.
+    ///
+    /// Modifications through this reference may also have an unspecified effect on further
+    /// iterator advancement, but implementations are encouraged to document this.
+    fn get_mut(&mut self) -> Option<&mut Self::Item>;
 
+    /// Advances the iterator and returns the next mutable value.
+    ///
+    /// The behavior of calling this method after the end of the iterator has been reached is
+    /// unspecified.
+    ///
+    /// The default implementation simply calls `advance` followed by `get_mut`.
     #[inline]
-    fn advance(&mut self) {
-        if self.first {
-            self.first = false;
-            self.iter.advance();
-        }
-        while let Some(iter) = self.iter.get_mut() {
-            iter.advance();
-            if !iter.is_done() {
-                break;
-            }
-            self.iter.advance(); // since we got Some, self.iter is not done and can be advanced
-        }
+    fn next_mut(&mut self) -> Option<&mut Self::Item> {
+        self.advance();
+        (*self).get_mut()
     }
 
+    /// Reduces the iterator's mutable elements to a single, final value.
     #[inline]
-    fn is_done(&self) -> bool {
-        match self.iter.get() {
-            Some(iter) => iter.is_done(),
-            None => true,
+    fn fold_mut<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, &mut Self::Item) -> B,
+    {
+        let mut acc = init;
+        while let Some(item) = self.next_mut() {
+            acc = f(acc, item);
         }
+        acc
     }
 
+    /// Calls a closure on each mutable element of an iterator.
     #[inline]
-    fn get(&self) -> Option<&Self::Item> {
-        self.iter.get().and_then(I::Item::get)
+    fn for_each_mut<F>(self, mut f: F)
+    where
+        Self: Sized,
+        F: FnMut(&mut Self::Item),
+    {
+        self.fold_mut((), move |(), item| f(item));
     }
 
-    #[inline]
-    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    /// Calls a fallible closure on each mutable element of an iterator, stopping at the first
+    /// error.
+    ///
+    /// As with `try_for_each`, the iterator is left positioned on the element which caused the
+    /// error, keeping any changes the closure made to it.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert_mut, StreamingIterator, StreamingIteratorMut};
+    /// let mut items = [1, 2, -1, 3];
+    /// let mut it = convert_mut(&mut items);
+    /// let res = it.try_for_each_mut(|i| {
+    ///     if *i < 0 {
+    ///         return Err(*i);
+    ///     }
+    ///     *i *= 2;
+    ///     Ok(())
+    /// });
+    /// assert_eq!(res, Err(-1));
+    /// assert_eq!(it.get(), Some(&-1));
+    /// assert_eq!(items, [2, 4, -1, 3]);
+    /// ```
+    #[inline]
+    fn try_for_each_mut<E, F>(&mut self, mut f: F) -> Result<(), E>
     where
         Self: Sized,
-        Fold: FnMut(Acc, &Self::Item) -> Acc,
+        F: FnMut(&mut Self::Item) -> Result<(), E>,
     {
-        self.iter
-            .fold_mut(init, |acc, item| item.fold(acc, &mut fold))
+        while let Some(item) = self.next_mut() {
+            f(item)?;
+        }
+        Ok(())
     }
-}
 
-impl<I> StreamingIteratorMut for Flatten<I>
-where
-    I: StreamingIteratorMut,
-    I::Item: StreamingIteratorMut,
-{
-    #[inline]
-    fn get_mut(&mut self) -> Option<&mut Self::Item> {
-        self.iter.get_mut().and_then(I::Item::get_mut)
+    /// Reduces the iterator's elements to a single value by folding each element into an
+    /// accumulator in place.
+    ///
+    /// The accumulator is seeded with a clone of the first element, and the closure then merges
+    /// each subsequent element into it. Returns `None` if the iterator is empty.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIteratorMut};
+    /// let rows = [[1, 5], [4, 2]];
+    /// let max = convert(rows).reduce_mut(|acc, row| {
+    ///     for (a, &b) in acc.iter_mut().zip(row) {
+    ///         *a = std::cmp::max(*a, b);
+    ///     }
+    /// });
+    /// assert_eq!(max, Some([4, 5]));
+    /// ```
+    #[inline]
+    fn reduce_mut<F>(mut self, mut f: F) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(&mut Self::Item, &Self::Item),
+    {
+        let mut acc = self.next()?.clone();
+        self.fold_mut((), |(), item| f(&mut acc, item));
+        Some(acc)
+    }
+
+    /// Borrows an iterator, rather than consuming it, keeping access to its elements mutable.
+    ///
+    /// This is the same as `by_ref`: `&mut I` implements `StreamingIteratorMut` whenever `I`
+    /// does, so adaptors applied to the borrowed iterator can still mutate its elements.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert_mut, StreamingIterator, StreamingIteratorMut};
+    /// let mut items = [1, 2, 3, 4];
+    /// let mut it = convert_mut(&mut items);
+    /// it.by_ref_mut().take(2).for_each_mut(|i| *i *= 10);
+    /// assert_eq!(it.next(), Some(&3));
+    /// assert_eq!(items, [10, 20, 3, 4]);
+    /// ```
+    #[inline]
+    fn by_ref_mut(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
     }
 
+    /// Creates a regular, non-streaming iterator which transforms mutable elements
+    /// of this iterator by passing them to a closure.
     #[inline]
-    fn fold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    fn map_deref_mut<B, F>(self, f: F) -> MapDerefMut<Self, F>
     where
         Self: Sized,
-        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+        F: FnMut(&mut Self::Item) -> B,
     {
-        self.iter
-            .fold_mut(init, |acc, item| item.fold_mut(acc, &mut fold))
+        MapDerefMut { it: self, f }
     }
-}
 
-/// A regular, non-streaming iterator which both filters and maps elements of a streaming iterator with a closure.
-#[derive(Debug)]
-pub struct FilterMapDeref<I, F> {
-    it: I,
-    f: F,
-}
+    /// Creates an iterator which projects elements of this iterator to references into them,
+    /// allowing the projected values to be mutated.
+    ///
+    /// This is the mutable counterpart of `map_ref`. Since `get` only has shared access to the
+    /// element, a shared projection `f` is needed alongside the mutable projection `f_mut`, and
+    /// the two should select the same value.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert_mut, StreamingIterator, StreamingIteratorMut};
+    /// let mut pairs = [(1, 'a'), (2, 'b')];
+    /// convert_mut(&mut pairs)
+    ///     .map_ref_mut(|p| &p.0, |p| &mut p.0)
+    ///     .for_each_mut(|n| *n *= 10);
+    /// assert_eq!(pairs, [(10, 'a'), (20, 'b')]);
+    /// ```
+    #[inline]
+    fn map_ref_mut<B: ?Sized, F, G>(self, f: F, f_mut: G) -> MapRefMut<Self, F, G>
+    where
+        Self: Sized,
+        F: Fn(&Self::Item) -> &B,
+        G: FnMut(&mut Self::Item) -> &mut B,
+    {
+        MapRefMut { it: self, f, f_mut }
+    }
 
-impl<I, B, F> Iterator for FilterMapDeref<I, F>
-where
-    I: StreamingIterator,
-    F: FnMut(&I::Item) -> Option<B>,
-{
-    type Item = B;
+    /// Creates an iterator which maps mutable elements to mutable streaming iterators with a
+    /// closure and then yields the concatenation of the obtained iterators.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert_mut, StreamingIteratorMut};
+    /// let mut a = [1, 2];
+    /// let mut b = [3];
+    /// let mut slices = [&mut a[..], &mut b[..]];
+    /// convert_mut(&mut slices)
+    ///     .flat_map_mut(|s| convert_mut(core::mem::take(s)))
+    ///     .for_each_mut(|i| *i *= 10);
+    /// assert_eq!(a, [10, 20]);
+    /// assert_eq!(b, [30]);
+    /// ```
+    #[inline]
+    fn flat_map_mut<J, F>(self, f: F) -> FlatMapMut<Self, J, F>
+    where
+        Self: Sized,
+        J: StreamingIteratorMut,
+        F: FnMut(&mut Self::Item) -> J,
+    {
+        FlatMapMut {
+            it: self,
+            f,
+            sub_iter: None,
+        }
+    }
 
+    /// Creates an iterator which flattens nested streaming iterators.
     #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        while let Some(item) = self.it.next() {
-            if let Some(mapped) = (self.f)(item) {
-                return Some(mapped);
-            }
+    fn flatten(self) -> Flatten<Self>
+    where
+        Self: Sized,
+        Self::Item: StreamingIterator,
+    {
+        Flatten {
+            iter: self,
+            first: true,
         }
+    }
 
-        None
+    /// Creates an iterator which flattens nested streaming iterators while keeping track of which
+    /// outer element the current inner element came from.
+    ///
+    /// The index of the outer element is available through `FlattenIndexed::outer_index`, or
+    /// together with the inner element through `FlattenIndexed::get_indexed` and
+    /// `FlattenIndexed::next_indexed`. Outer elements which yield no inner elements are counted
+    /// but never observed.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator, StreamingIteratorMut};
+    /// let groups = [convert(vec![1, 2]), convert(vec![]), convert(vec![3])];
+    /// let mut it = convert(groups).flatten_indexed();
+    /// assert_eq!(it.next_indexed(), Some((0, &1)));
+    /// assert_eq!(it.next_indexed(), Some((0, &2)));
+    /// assert_eq!(it.next_indexed(), Some((2, &3)));
+    /// assert_eq!(it.next_indexed(), None);
+    /// ```
+    #[inline]
+    fn flatten_indexed(self) -> FlattenIndexed<Self>
+    where
+        Self: Sized,
+        Self::Item: StreamingIterator,
+    {
+        FlattenIndexed {
+            iter: self,
+            first: true,
+            outer: 0,
+        }
     }
 
-    #[inline]
-    fn fold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
+    /// Creates an iterator which pairs the elements of this iterator with those of another
+    /// mutable streaming iterator.
+    ///
+    /// Iteration stops when either iterator is exhausted. Since a pair of mutable references
+    /// cannot be yielded as a single element, mutable access to both halves at once is provided
+    /// by `ZipMut::get_mut` and `ZipMut::next_mut`, while the `StreamingIterator` implementation
+    /// yields the elements of this iterator.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert_mut, StreamingIteratorMut};
+    /// let mut a = [1, 2, 3];
+    /// let mut b = [4, 5];
+    /// let mut it = convert_mut(&mut a).zip_mut(convert_mut(&mut b));
+    /// while let Some((x, y)) = it.next_mut() {
+    ///     core::mem::swap(x, y);
+    /// }
+    /// assert_eq!(a, [4, 5, 3]);
+    /// assert_eq!(b, [1, 2]);
+    /// ```
+    #[inline]
+    fn zip_mut<J>(self, other: J) -> ZipMut<Self, J>
     where
         Self: Sized,
-        Fold: FnMut(Acc, Self::Item) -> Acc,
+        J: StreamingIteratorMut,
     {
-        let mut map = self.f;
-        self.it.fold(init, move |acc, item| match map(item) {
-            Some(mapped) => f(acc, mapped),
-            None => acc,
-        })
+        ZipMut {
+            a: self,
+            b: other,
+            done: false,
+        }
     }
 }
 
-impl<I, B, F> DoubleEndedIterator for FilterMapDeref<I, F>
+impl<I: ?Sized> StreamingIteratorMut for &mut I
 where
-    I: DoubleEndedStreamingIterator,
-    F: FnMut(&I::Item) -> Option<B>,
+    I: StreamingIteratorMut,
 {
     #[inline]
-    fn next_back(&mut self) -> Option<B> {
-        while let Some(item) = self.it.next_back() {
-            if let Some(mapped) = (self.f)(item) {
-                return Some(mapped);
-            }
-        }
-
-        None
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        (**self).get_mut()
     }
 
     #[inline]
-    fn rfold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, Self::Item) -> Acc,
-    {
-        let mut map = self.f;
-        self.it.rfold(init, move |acc, item| match map(item) {
-            Some(mapped) => f(acc, mapped),
-            None => acc,
-        })
+    fn next_mut(&mut self) -> Option<&mut Self::Item> {
+        (**self).next_mut()
     }
 }
 
-#[derive(Copy, Clone, Debug)]
-enum FuseState {
-    Start,
-    Middle,
-    End,
-}
-
-/// A streaming iterator which is well-defined before and after iteration.
-#[derive(Clone, Debug)]
-pub struct Fuse<I> {
-    it: I,
-    state: FuseState,
+#[cfg(feature = "alloc")]
+impl<I: ?Sized> StreamingIteratorMut for Box<I>
+where
+    I: StreamingIteratorMut,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        (**self).get_mut()
+    }
+
+    #[inline]
+    fn next_mut(&mut self) -> Option<&mut Self::Item> {
+        (**self).next_mut()
+    }
 }
 
-impl<I> StreamingIterator for Fuse<I>
+/// A mutable streaming iterator able to yield elements from both ends.
+///
+/// All of its methods have default implementations, so an iterator which implements both
+/// `DoubleEndedStreamingIterator` and `StreamingIteratorMut` can opt in with an empty impl.
+pub trait DoubleEndedStreamingIteratorMut:
+    DoubleEndedStreamingIterator + StreamingIteratorMut
+{
+    /// Advances the iterator and returns the next mutable value from the back.
+    ///
+    /// The behavior of calling this method after the end of the iterator has been reached is
+    /// unspecified.
+    ///
+    /// The default implementation simply calls `advance_back` followed by `get_mut`.
+    #[inline]
+    fn next_back_mut(&mut self) -> Option<&mut Self::Item> {
+        self.advance_back();
+        (*self).get_mut()
+    }
+
+    /// Reduces the iterator's mutable elements to a single, final value, starting from the back.
+    #[inline]
+    fn rfold_mut<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, &mut Self::Item) -> B,
+    {
+        let mut acc = init;
+        while let Some(item) = self.next_back_mut() {
+            acc = f(acc, item);
+        }
+        acc
+    }
+}
+// Note, in theory we could blanket-impl `DoubleEndedStreamingIteratorMut`, but that
+// wouldn't allow custom folding until we can do it with Rust specialization.
+
+/// A streaming iterator which knows its exact length.
+pub trait ExactSizeStreamingIterator: StreamingIterator {
+    /// Returns the exact number of elements remaining in the iterator.
+    ///
+    /// The default implementation relies on `size_hint` returning equal lower and upper bounds.
+    #[inline]
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(Some(lower), upper);
+        lower
+    }
+
+    /// Returns `true` if the iterator has no elements remaining.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A streaming iterator whose remaining elements can be accessed by position without advancing.
+///
+/// This is implemented by iterators backed by a slice, which also use it to make `nth` skip
+/// directly to an element rather than stepping through the ones before it.
+pub trait IndexedStreamingIterator: StreamingIterator {
+    /// Returns a reference to the element that `nth(index)` would return, without advancing the
+    /// iterator.
+    ///
+    /// `index` is counted from the front of the remaining elements, so `get_at(0)` is the element
+    /// the next call to `next` would return.
+    ///
+    /// ```
+    /// # use streaming_iterator::{windows, IndexedStreamingIterator, StreamingIterator};
+    /// let items = [1, 2, 3, 4];
+    /// let mut it = windows(&items, 2);
+    /// assert_eq!(it.get_at(1), Some(&[2, 3][..]));
+    /// it.advance();
+    /// assert_eq!(it.get_at(1), Some(&[3, 4][..]));
+    /// assert_eq!(it.get_at(2), None);
+    /// ```
+    fn get_at(&self, index: usize) -> Option<&Self::Item>;
+}
+
+/// Whether `StreamingIterator::fold_while` should continue folding, carrying the accumulator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FoldWhile<B> {
+    /// Continue folding with the contained accumulator.
+    Continue(B),
+    /// Stop folding, returning the contained accumulator.
+    Done(B),
+}
+
+/// A streaming iterator that concatenates two streaming iterators
+#[derive(Clone, Debug)]
+pub struct Chain<A, B> {
+    a: A,
+    b: B,
+    state: ChainState,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum ChainState {
+    // Both iterators have items remaining and we are iterating forward
+    BothForward,
+    // Both iterators have items remaining and we are iterating backward
+    BothBackward,
+    // Only the front iterator has items
+    Front,
+    // Only the back iterator has items
+    Back,
+}
+
+impl<A, B> StreamingIterator for Chain<A, B>
 where
-    I: StreamingIterator,
+    A: StreamingIterator,
+    B: StreamingIterator<Item = A::Item>,
 {
-    type Item = I::Item;
+    type Item = A::Item;
 
     #[inline]
     fn advance(&mut self) {
+        use crate::ChainState::*;
+
         match self.state {
-            FuseState::Start => {
-                self.it.advance();
-                self.state = if self.it.is_done() {
-                    FuseState::End
+            BothForward | BothBackward => {
+                self.a.advance();
+                self.state = if self.a.is_done() {
+                    self.b.advance();
+                    Back
                 } else {
-                    FuseState::Middle
+                    BothForward
                 };
             }
-            FuseState::Middle => {
-                self.it.advance();
-                if self.it.is_done() {
-                    self.state = FuseState::End;
-                }
-            }
-            FuseState::End => {}
+            Front => self.a.advance(),
+            Back => self.b.advance(),
         }
     }
 
     #[inline]
     fn is_done(&self) -> bool {
-        match self.state {
-            FuseState::Start | FuseState::End => true,
-            FuseState::Middle => false,
-        }
-    }
+        use crate::ChainState::*;
 
-    #[inline]
-    fn get(&self) -> Option<&I::Item> {
         match self.state {
-            FuseState::Start | FuseState::End => None,
-            FuseState::Middle => self.it.get(),
+            BothForward | Front => self.a.is_done(),
+            BothBackward | Back => self.b.is_done(),
         }
     }
 
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.it.size_hint()
-    }
+    fn get(&self) -> Option<&Self::Item> {
+        use crate::ChainState::*;
 
-    #[inline]
-    fn next(&mut self) -> Option<&I::Item> {
         match self.state {
-            FuseState::Start => match self.it.next() {
-                Some(i) => {
-                    self.state = FuseState::Middle;
-                    Some(i)
-                }
-                None => {
-                    self.state = FuseState::End;
-                    None
-                }
-            },
-            FuseState::Middle => match self.it.next() {
-                Some(i) => Some(i),
-                None => {
-                    self.state = FuseState::End;
-                    None
-                }
-            },
-            FuseState::End => None,
+            BothForward | Front => self.a.get(),
+            BothBackward | Back => self.b.get(),
         }
     }
 
     #[inline]
-    fn count(self) -> usize {
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        use crate::ChainState::*;
+
         match self.state {
-            FuseState::Start | FuseState::Middle => self.it.count(),
-            FuseState::End => 0,
+            BothForward | BothBackward => {
+                let (a_lower, a_upper) = self.a.size_hint();
+                let (b_lower, b_upper) = self.b.size_hint();
+                let lower = a_lower.saturating_add(b_lower);
+                let upper = match (a_upper, b_upper) {
+                    (Some(a), Some(b)) => a.checked_add(b),
+                    _ => None,
+                };
+                (lower, upper)
+            }
+            Front => self.a.size_hint(),
+            Back => self.b.size_hint(),
         }
     }
 
     #[inline]
-    fn fold<Acc, Fold>(self, init: Acc, fold: Fold) -> Acc
+    fn fold<Acc, F>(self, init: Acc, mut f: F) -> Acc
     where
         Self: Sized,
-        Fold: FnMut(Acc, &Self::Item) -> Acc,
+        F: FnMut(Acc, &Self::Item) -> Acc,
     {
+        let mut accum = init;
         match self.state {
-            FuseState::Start | FuseState::Middle => self.it.fold(init, fold),
-            FuseState::End => init,
+            ChainState::Back => {}
+            _ => accum = self.a.fold(accum, &mut f),
+        }
+        match self.state {
+            ChainState::Front => {}
+            _ => accum = self.b.fold(accum, &mut f),
         }
+        accum
     }
 }
 
-impl<I> StreamingIteratorMut for Fuse<I>
+impl<A, B> DoubleEndedStreamingIterator for Chain<A, B>
 where
-    I: StreamingIteratorMut,
+    A: DoubleEndedStreamingIterator,
+    B: DoubleEndedStreamingIterator<Item = A::Item>,
 {
     #[inline]
-    fn get_mut(&mut self) -> Option<&mut I::Item> {
+    fn advance_back(&mut self) {
+        use crate::ChainState::*;
+
         match self.state {
-            FuseState::Start | FuseState::End => None,
-            FuseState::Middle => self.it.get_mut(),
+            BothForward | BothBackward => {
+                self.b.advance_back();
+                self.state = if self.b.is_done() {
+                    self.a.advance_back();
+                    Front
+                } else {
+                    BothBackward
+                };
+            }
+            Front => self.a.advance_back(),
+            Back => self.b.advance_back(),
         }
     }
 
     #[inline]
-    fn fold_mut<Acc, Fold>(self, init: Acc, fold: Fold) -> Acc
+    fn rfold<Acc, F>(self, init: Acc, mut f: F) -> Acc
     where
         Self: Sized,
-        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+        F: FnMut(Acc, &Self::Item) -> Acc,
     {
+        let mut accum = init;
         match self.state {
-            FuseState::Start | FuseState::Middle => self.it.fold_mut(init, fold),
-            FuseState::End => init,
+            ChainState::Front => {}
+            _ => accum = self.b.rfold(accum, &mut f),
+        }
+        match self.state {
+            ChainState::Back => {}
+            _ => accum = self.a.rfold(accum, &mut f),
         }
+        accum
     }
 }
 
-/// A streaming iterator that calls a function with element before yielding it.
-#[derive(Debug)]
-pub struct Inspect<I, F> {
-    it: I,
-    f: F,
-}
-
-impl<I, F> StreamingIterator for Inspect<I, F>
+impl<A, B> StreamingIteratorMut for Chain<A, B>
 where
-    I: StreamingIterator,
-    F: FnMut(&I::Item),
+    A: StreamingIteratorMut,
+    B: StreamingIteratorMut<Item = A::Item>,
 {
-    type Item = I::Item;
-
-    fn advance(&mut self) {
-        if let Some(item) = self.it.next() {
-            (self.f)(item);
-        }
-    }
-
     #[inline]
-    fn is_done(&self) -> bool {
-        self.it.is_done()
-    }
-
-    fn get(&self) -> Option<&Self::Item> {
-        self.it.get()
-    }
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        use crate::ChainState::*;
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.it.size_hint()
+        match self.state {
+            BothForward | Front => self.a.get_mut(),
+            BothBackward | Back => self.b.get_mut(),
+        }
     }
 
     #[inline]
-    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    fn fold_mut<Acc, F>(self, init: Acc, mut f: F) -> Acc
     where
         Self: Sized,
-        Fold: FnMut(Acc, &Self::Item) -> Acc,
+        F: FnMut(Acc, &mut Self::Item) -> Acc,
     {
-        let mut f = self.f;
-        self.it.fold(init, |acc, item| {
-            f(item);
-            fold(acc, item)
-        })
+        let mut accum = init;
+        match self.state {
+            ChainState::Back => {}
+            _ => accum = self.a.fold_mut(accum, &mut f),
+        }
+        match self.state {
+            ChainState::Front => {}
+            _ => accum = self.b.fold_mut(accum, &mut f),
+        }
+        accum
     }
 }
 
-impl<I, F> DoubleEndedStreamingIterator for Inspect<I, F>
+impl<A, B> DoubleEndedStreamingIteratorMut for Chain<A, B>
 where
-    I: DoubleEndedStreamingIterator,
-    F: FnMut(&I::Item),
+    A: DoubleEndedStreamingIteratorMut,
+    B: DoubleEndedStreamingIteratorMut<Item = A::Item>,
 {
-    fn advance_back(&mut self) {
-        if let Some(item) = self.it.next_back() {
-            (self.f)(item);
-        }
-    }
-
-    #[inline]
-    fn rfold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    fn rfold_mut<Acc, F>(self, init: Acc, mut f: F) -> Acc
     where
         Self: Sized,
-        Fold: FnMut(Acc, &Self::Item) -> Acc,
+        F: FnMut(Acc, &mut Self::Item) -> Acc,
     {
-        let mut f = self.f;
-        self.it.rfold(init, |acc, item| {
-            f(item);
-            fold(acc, item)
-        })
+        let mut accum = init;
+        match self.state {
+            ChainState::Front => {}
+            _ => accum = self.b.rfold_mut(accum, &mut f),
+        }
+        match self.state {
+            ChainState::Back => {}
+            _ => accum = self.a.rfold_mut(accum, &mut f),
+        }
+        accum
     }
 }
 
-impl<I, F> StreamingIteratorMut for Inspect<I, F>
+/// A streaming iterator over runs of consecutive elements of a streaming iterator which map to
+/// equal keys, each of which is itself a streaming iterator.
+#[derive(Clone, Debug)]
+pub struct ChunkBy<I, K, F> {
+    group: Group<I, K, F>,
+    first: bool,
+    active: bool,
+}
+
+impl<I, K, F> StreamingIterator for ChunkBy<I, K, F>
 where
-    I: StreamingIteratorMut,
-    F: FnMut(&I::Item),
+    I: StreamingIterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
 {
-    fn get_mut(&mut self) -> Option<&mut Self::Item> {
-        self.it.get_mut()
+    type Item = Group<I, K, F>;
+
+    #[inline]
+    fn advance(&mut self) {
+        let group = &mut self.group;
+        if self.first {
+            self.first = false;
+            group.it.advance();
+            group.key = group.it.get().map(&mut group.f);
+        } else {
+            while !group.ended {
+                group.advance();
+            }
+        }
+        self.active = group.it.get().is_some();
+        group.pending = self.active;
+        group.ended = !self.active;
     }
 
     #[inline]
-    fn fold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
-    {
-        let mut f = self.f;
-        self.it.fold_mut(init, |acc, item| {
-            f(&*item);
-            fold(acc, item)
-        })
+    fn is_done(&self) -> bool {
+        !self.active
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&Group<I, K, F>> {
+        if self.active {
+            Some(&self.group)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let upper = self.group.it.size_hint().1;
+        let next = !self.first && self.group.it.get().is_some();
+        (0, upper.and_then(|upper| upper.checked_add(next as usize)))
     }
 }
 
-impl<I, F> DoubleEndedStreamingIteratorMut for Inspect<I, F>
+impl<I, K, F> StreamingIteratorMut for ChunkBy<I, K, F>
 where
-    I: DoubleEndedStreamingIteratorMut,
-    F: FnMut(&I::Item),
+    I: StreamingIterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
 {
     #[inline]
-    fn rfold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
-    {
-        let mut f = self.f;
-        self.it.rfold_mut(init, |acc, item| {
-            f(&*item);
-            fold(acc, item)
-        })
+    fn get_mut(&mut self) -> Option<&mut Group<I, K, F>> {
+        if self.active {
+            Some(&mut self.group)
+        } else {
+            None
+        }
     }
 }
 
-/// A streaming iterator which transforms the elements of a streaming iterator.
-#[derive(Debug)]
-pub struct Map<I, B, F> {
+/// A streaming iterator over a run of consecutive elements which map to equal keys.
+///
+/// This struct is yielded by the [`ChunkBy`] iterator.
+#[derive(Clone, Debug)]
+pub struct Group<I, K, F> {
     it: I,
     f: F,
-    item: Option<B>,
+    key: Option<K>,
+    pending: bool,
+    ended: bool,
 }
 
-impl<I, B, F> StreamingIterator for Map<I, B, F>
+impl<I, K, F> StreamingIterator for Group<I, K, F>
 where
     I: StreamingIterator,
-    F: FnMut(&I::Item) -> B,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
 {
-    type Item = B;
+    type Item = I::Item;
 
     #[inline]
     fn advance(&mut self) {
-        self.item = self.it.next().map(&mut self.f);
+        if self.ended {
+            return;
+        }
+        if self.pending {
+            self.pending = false;
+            return;
+        }
+        self.it.advance();
+        match self.it.get() {
+            Some(item) => {
+                let key = (self.f)(item);
+                if self.key.as_ref() != Some(&key) {
+                    self.key = Some(key);
+                    self.ended = true;
+                }
+            }
+            None => self.ended = true,
+        }
     }
 
     #[inline]
-    fn get(&self) -> Option<&B> {
-        self.item.as_ref()
+    fn is_done(&self) -> bool {
+        self.ended
     }
 
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.it.size_hint()
+    fn get(&self) -> Option<&I::Item> {
+        if self.ended || self.pending {
+            None
+        } else {
+            self.it.get()
+        }
     }
 
     #[inline]
-    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, &Self::Item) -> Acc,
-    {
-        let mut f = self.f;
-        self.it.fold(init, move |acc, item| fold(acc, &f(item)))
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.ended {
+            return (0, Some(0));
+        }
+        let upper = self.it.size_hint().1;
+        let pending = self.pending as usize;
+        (pending, upper.and_then(|upper| upper.checked_add(pending)))
     }
 }
 
-impl<I, B, F> DoubleEndedStreamingIterator for Map<I, B, F>
+impl<I, K, F> StreamingIteratorMut for Group<I, K, F>
 where
-    I: DoubleEndedStreamingIterator,
-    F: FnMut(&I::Item) -> B,
+    I: StreamingIteratorMut,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
 {
     #[inline]
-    fn advance_back(&mut self) {
-        self.item = self.it.next_back().map(&mut self.f);
-    }
-
-    #[inline]
-    fn rfold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, &Self::Item) -> Acc,
-    {
-        let mut f = self.f;
-        self.it.rfold(init, move |acc, item| fold(acc, &f(item)))
+    fn get_mut(&mut self) -> Option<&mut I::Item> {
+        if self.ended || self.pending {
+            None
+        } else {
+            self.it.get_mut()
+        }
     }
 }
 
-impl<I, B, F> StreamingIteratorMut for Map<I, B, F>
+/// A streaming iterator over non-overlapping chunks of a streaming iterator, each of which is
+/// collected into an owned buffer.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub struct Chunks<I>
 where
     I: StreamingIterator,
-    F: FnMut(&I::Item) -> B,
+    I::Item: ToOwned,
 {
-    #[inline]
-    fn get_mut(&mut self) -> Option<&mut B> {
-        self.item.as_mut()
-    }
-
-    #[inline]
-    fn fold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
-    {
-        let mut f = self.f;
-        self.it.fold(init, move |acc, item| fold(acc, &mut f(item)))
-    }
+    it: I,
+    size: usize,
+    buf: Vec<<I::Item as ToOwned>::Owned>,
+    exhausted: bool,
 }
 
-impl<I, B, F> DoubleEndedStreamingIteratorMut for Map<I, B, F>
+// These can't be derived, since derives don't bound the owned element type.
+#[cfg(feature = "alloc")]
+impl<I> Clone for Chunks<I>
 where
-    I: DoubleEndedStreamingIterator,
-    F: FnMut(&I::Item) -> B,
+    I: StreamingIterator + Clone,
+    I::Item: ToOwned,
+    <I::Item as ToOwned>::Owned: Clone,
 {
     #[inline]
-    fn rfold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
-    {
-        let mut f = self.f;
-        self.it
-            .rfold(init, move |acc, item| fold(acc, &mut f(item)))
+    fn clone(&self) -> Self {
+        Chunks {
+            it: self.it.clone(),
+            size: self.size,
+            buf: self.buf.clone(),
+            exhausted: self.exhausted,
+        }
     }
 }
 
-/// A regular, non-streaming iterator which transforms the elements of a streaming iterator.
-#[derive(Debug)]
-pub struct MapDeref<I, F> {
-    it: I,
-    f: F,
+#[cfg(feature = "alloc")]
+impl<I> core::fmt::Debug for Chunks<I>
+where
+    I: StreamingIterator + core::fmt::Debug,
+    I::Item: ToOwned,
+    <I::Item as ToOwned>::Owned: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Chunks")
+            .field("it", &self.it)
+            .field("size", &self.size)
+            .field("buf", &self.buf)
+            .field("exhausted", &self.exhausted)
+            .finish()
+    }
 }
 
-impl<I, B, F> Iterator for MapDeref<I, F>
+#[cfg(feature = "alloc")]
+impl<I> StreamingIterator for Chunks<I>
 where
     I: StreamingIterator,
-    F: FnMut(&I::Item) -> B,
+    I::Item: ToOwned,
 {
-    type Item = B;
+    type Item = [<I::Item as ToOwned>::Owned];
 
     #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        self.it.next().map(&mut self.f)
+    fn advance(&mut self) {
+        self.buf.clear();
+        while !self.exhausted && self.buf.len() < self.size {
+            match self.it.next() {
+                Some(item) => self.buf.push(item.to_owned()),
+                None => self.exhausted = true,
+            }
+        }
     }
 
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.it.size_hint()
+    fn get(&self) -> Option<&Self::Item> {
+        if self.buf.is_empty() {
+            None
+        } else {
+            Some(&self.buf)
+        }
     }
 
     #[inline]
-    fn fold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, Self::Item) -> Acc,
-    {
-        let mut map = self.f;
-        self.it.fold(init, move |acc, item| f(acc, map(item)))
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.exhausted {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.it.size_hint();
+        let chunks = |n: usize| n / self.size + (n % self.size != 0) as usize;
+        (chunks(lower), upper.map(chunks))
     }
 }
 
-impl<I, B, F> DoubleEndedIterator for MapDeref<I, F>
+#[cfg(feature = "alloc")]
+impl<I> StreamingIteratorMut for Chunks<I>
 where
-    I: DoubleEndedStreamingIterator,
-    F: FnMut(&I::Item) -> B,
+    I: StreamingIterator,
+    I::Item: ToOwned,
 {
     #[inline]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.it.next_back().map(&mut self.f)
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        if self.buf.is_empty() {
+            None
+        } else {
+            Some(&mut self.buf)
+        }
     }
+}
 
+/// A streaming iterator over non-overlapping chunks of a streaming iterator, each of which is
+/// itself a streaming iterator.
+#[derive(Clone, Debug)]
+pub struct ChunksExact<I> {
+    chunk: Take<I>,
+    size: usize,
+    chunks: usize,
+    rem: usize,
+    active: bool,
+}
+
+impl<I> ChunksExact<I> {
+    /// Returns the number of trailing elements of the underlying iterator which do not fill a
+    /// chunk and so will not be yielded.
     #[inline]
-    fn rfold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, Self::Item) -> Acc,
-    {
-        let mut map = self.f;
-        self.it.rfold(init, move |acc, item| f(acc, map(item)))
+    pub fn remainder_len(&self) -> usize {
+        self.rem
     }
 }
 
-/// A regular, non-streaming iterator which transforms the elements of a mutable streaming iterator.
-#[derive(Debug)]
-pub struct MapDerefMut<I, F> {
-    it: I,
-    f: F,
-}
-
-impl<I, B, F> Iterator for MapDerefMut<I, F>
+impl<I> StreamingIterator for ChunksExact<I>
 where
-    I: StreamingIteratorMut,
-    F: FnMut(&mut I::Item) -> B,
+    I: StreamingIterator,
 {
-    type Item = B;
+    type Item = Take<I>;
 
     #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        self.it.next_mut().map(&mut self.f)
+    fn advance(&mut self) {
+        if self.active {
+            while self.chunk.n > 0 {
+                self.chunk.it.advance();
+                self.chunk.n -= 1;
+            }
+        }
+        self.active = self.chunks > 0;
+        if self.active {
+            self.chunks -= 1;
+            self.chunk.n = self.size;
+            self.chunk.done = false;
+        }
     }
 
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.it.size_hint()
+    fn is_done(&self) -> bool {
+        !self.active
     }
 
     #[inline]
-    fn fold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, Self::Item) -> Acc,
-    {
-        let mut map = self.f;
-        self.it.fold_mut(init, move |acc, item| f(acc, map(item)))
+    fn get(&self) -> Option<&Take<I>> {
+        if self.active {
+            Some(&self.chunk)
+        } else {
+            None
+        }
     }
-}
 
-impl<I, B, F> DoubleEndedIterator for MapDerefMut<I, F>
-where
-    I: DoubleEndedStreamingIteratorMut,
-    F: FnMut(&mut I::Item) -> B,
-{
     #[inline]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.it.next_back_mut().map(&mut self.f)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.chunks, Some(self.chunks))
     }
+}
+
+impl<I> ExactSizeStreamingIterator for ChunksExact<I> where I: StreamingIterator {}
 
+impl<I> StreamingIteratorMut for ChunksExact<I>
+where
+    I: StreamingIterator,
+{
     #[inline]
-    fn rfold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, Self::Item) -> Acc,
-    {
-        let mut map = self.f;
-        self.it.rfold_mut(init, move |acc, item| f(acc, map(item)))
+    fn get_mut(&mut self) -> Option<&mut Take<I>> {
+        if self.active {
+            Some(&mut self.chunk)
+        } else {
+            None
+        }
     }
 }
 
-/// A streaming iterator which transforms the elements of a streaming iterator.
-#[derive(Debug)]
-pub struct MapRef<I, F> {
-    it: I,
-    f: F,
-}
+/// A normal, non-streaming, iterator which converts the elements of a streaming iterator into owned
+/// values by cloning them.
+#[derive(Clone, Debug)]
+pub struct Cloned<I>(I);
 
-impl<I, B: ?Sized, F> StreamingIterator for MapRef<I, F>
+impl<I> Iterator for Cloned<I>
 where
     I: StreamingIterator,
-    F: Fn(&I::Item) -> &B,
+    I::Item: Clone,
 {
-    type Item = B;
-
-    #[inline]
-    fn advance(&mut self) {
-        self.it.advance();
-    }
+    type Item = I::Item;
 
     #[inline]
-    fn is_done(&self) -> bool {
-        self.it.is_done()
+    fn next(&mut self) -> Option<I::Item> {
+        self.0.next().cloned()
     }
 
     #[inline]
-    fn get(&self) -> Option<&B> {
-        self.it.get().map(&self.f)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
     }
 
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.it.size_hint()
+    fn fold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.0.fold(init, move |acc, item| f(acc, item.clone()))
     }
+}
 
+impl<I> DoubleEndedIterator for Cloned<I>
+where
+    I: DoubleEndedStreamingIterator,
+    I::Item: Clone,
+{
     #[inline]
-    fn next(&mut self) -> Option<&B> {
-        self.it.next().map(&self.f)
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.0.next_back().cloned()
     }
 
     #[inline]
-    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    fn rfold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
     where
         Self: Sized,
-        Fold: FnMut(Acc, &Self::Item) -> Acc,
+        Fold: FnMut(Acc, Self::Item) -> Acc,
     {
-        let f = self.f;
-        self.it.fold(init, move |acc, item| fold(acc, f(item)))
+        self.0.rfold(init, move |acc, item| f(acc, item.clone()))
     }
 }
 
 /// A normal, non-streaming, iterator which converts the elements of a streaming iterator into owned
-/// versions.
-///
-/// Requires the `alloc` feature.
-#[cfg(feature = "alloc")]
+/// values by copying them.
 #[derive(Clone, Debug)]
-pub struct Owned<I>(I);
+pub struct Copied<I>(I);
 
-#[cfg(feature = "alloc")]
-impl<I> Iterator for Owned<I>
+impl<I> Iterator for Copied<I>
 where
     I: StreamingIterator,
-    I::Item: ToOwned,
+    I::Item: Copy,
 {
-    type Item = <I::Item as ToOwned>::Owned;
+    type Item = I::Item;
 
     #[inline]
-    fn next(&mut self) -> Option<<I::Item as ToOwned>::Owned> {
-        self.0.next().map(ToOwned::to_owned)
+    fn next(&mut self) -> Option<I::Item> {
+        self.0.next().copied()
     }
 
     #[inline]
@@ -2035,19 +3149,18 @@ where
         Self: Sized,
         Fold: FnMut(Acc, Self::Item) -> Acc,
     {
-        self.0.fold(init, move |acc, item| f(acc, item.to_owned()))
+        self.0.fold(init, move |acc, &item| f(acc, item))
     }
 }
 
-#[cfg(feature = "alloc")]
-impl<I> DoubleEndedIterator for Owned<I>
+impl<I> DoubleEndedIterator for Copied<I>
 where
     I: DoubleEndedStreamingIterator,
-    I::Item: Sized + ToOwned,
+    I::Item: Copy,
 {
     #[inline]
-    fn next_back(&mut self) -> Option<<I::Item as ToOwned>::Owned> {
-        self.0.next_back().map(ToOwned::to_owned)
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.0.next_back().copied()
     }
 
     #[inline]
@@ -2056,27 +3169,30 @@ where
         Self: Sized,
         Fold: FnMut(Acc, Self::Item) -> Acc,
     {
-        self.0.rfold(init, move |acc, item| f(acc, item.to_owned()))
+        self.0.rfold(init, move |acc, &item| f(acc, item))
     }
 }
 
-/// A streaming iterator which skips a number of elements in a streaming iterator.
+/// A streaming iterator which repeats the elements of a streaming iterator endlessly.
 #[derive(Clone, Debug)]
-pub struct Skip<I> {
+pub struct Cycle<I> {
+    orig: I,
     it: I,
-    n: usize,
 }
 
-impl<I> StreamingIterator for Skip<I>
+impl<I> StreamingIterator for Cycle<I>
 where
-    I: StreamingIterator,
+    I: StreamingIterator + Clone,
 {
     type Item = I::Item;
 
     #[inline]
     fn advance(&mut self) {
-        self.it.nth(self.n);
-        self.n = 0;
+        self.it.advance();
+        if self.it.is_done() {
+            self.it = self.orig.clone();
+            self.it.advance();
+        }
     }
 
     #[inline]
@@ -2091,76 +3207,58 @@ where
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let hint = self.it.size_hint();
-        (
-            hint.0.saturating_sub(self.n),
-            hint.1.map(|n| n.saturating_sub(self.n)),
-        )
-    }
-
-    #[inline]
-    fn fold<Acc, Fold>(mut self, init: Acc, fold: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, &Self::Item) -> Acc,
-    {
-        if self.n > 0 {
-            // nth(n) skips n+1
-            if self.it.nth(self.n - 1).is_none() {
-                return init;
-            }
+        match self.orig.size_hint() {
+            (0, Some(0)) => (0, Some(0)),
+            (0, _) => (0, None),
+            _ => (usize::MAX, None),
         }
-        self.it.fold(init, fold)
     }
 }
 
-impl<I> StreamingIteratorMut for Skip<I>
+impl<I> StreamingIteratorMut for Cycle<I>
 where
-    I: StreamingIteratorMut,
+    I: StreamingIteratorMut + Clone,
 {
-    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut I::Item> {
         self.it.get_mut()
     }
+}
 
-    #[inline]
-    fn fold_mut<Acc, Fold>(mut self, init: Acc, fold: Fold) -> Acc
-    where
-        Self: Sized,
-        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
-    {
-        if self.n > 0 {
-            // nth(n) skips n+1
-            if self.it.nth(self.n - 1).is_none() {
-                return init;
-            }
-        }
-        self.it.fold_mut(init, fold)
-    }
+// Once an element has been yielded, the rest may all be duplicates of it.
+#[inline]
+fn dedup_size_hint(hint: (usize, Option<usize>), started: bool) -> (usize, Option<usize>) {
+    let (lower, upper) = hint;
+    let lower = if started { 0 } else { cmp::min(lower, 1) };
+    (lower, upper)
 }
 
-/// A streaming iterator which skips initial elements that match a predicate
+/// A streaming iterator which skips consecutive duplicate elements of a streaming iterator.
 #[derive(Clone, Debug)]
-pub struct SkipWhile<I, F> {
+pub struct Dedup<I>
+where
+    I: StreamingIterator,
+    I::Item: Sized,
+{
     it: I,
-    f: F,
-    done: bool,
+    front: Option<I::Item>,
+    back: Option<I::Item>,
 }
 
-impl<I, F> StreamingIterator for SkipWhile<I, F>
+impl<I> StreamingIterator for Dedup<I>
 where
     I: StreamingIterator,
-    F: FnMut(&I::Item) -> bool,
+    I::Item: PartialEq + Clone,
 {
     type Item = I::Item;
 
     #[inline]
     fn advance(&mut self) {
-        if !self.done {
-            let f = &mut self.f;
-            self.it.find(|i| !f(i));
-            self.done = true;
-        } else {
-            self.it.advance();
+        while let Some(item) = self.it.next() {
+            if self.front.as_ref() != Some(item) {
+                self.front = Some(item.clone());
+                break;
+            }
         }
     }
 
@@ -2176,228 +3274,381 @@ where
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let hint = self.it.size_hint();
-        (0, hint.1)
+        let started = self.front.is_some() || self.back.is_some();
+        dedup_size_hint(self.it.size_hint(), started)
     }
 
     #[inline]
-    fn fold<Acc, Fold>(mut self, mut init: Acc, mut fold: Fold) -> Acc
+    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
     where
         Self: Sized,
         Fold: FnMut(Acc, &Self::Item) -> Acc,
     {
-        if !self.done {
-            match self.next() {
-                Some(item) => init = fold(init, item),
-                None => return init,
+        let mut last = self.front;
+        self.it.fold(init, move |acc, item| {
+            if last.as_ref() == Some(item) {
+                acc
+            } else {
+                last = Some(item.clone());
+                fold(acc, item)
             }
-        }
-        self.it.fold(init, fold)
+        })
     }
 }
 
-impl<I, F> StreamingIteratorMut for SkipWhile<I, F>
+impl<I> DoubleEndedStreamingIterator for Dedup<I>
 where
-    I: StreamingIteratorMut,
-    F: FnMut(&I::Item) -> bool,
+    I: DoubleEndedStreamingIterator,
+    I::Item: PartialEq + Clone,
 {
-    fn get_mut(&mut self) -> Option<&mut Self::Item> {
-        self.it.get_mut()
+    #[inline]
+    fn advance_back(&mut self) {
+        while let Some(item) = self.it.next_back() {
+            if self.back.as_ref() != Some(item) {
+                self.back = Some(item.clone());
+                break;
+            }
+        }
     }
 
     #[inline]
-    fn fold_mut<Acc, Fold>(mut self, mut init: Acc, mut fold: Fold) -> Acc
+    fn rfold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
     where
         Self: Sized,
-        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
     {
-        if !self.done {
-            match self.next_mut() {
-                Some(item) => init = fold(init, item),
-                None => return init,
+        let mut last = self.back;
+        self.it.rfold(init, move |acc, item| {
+            if last.as_ref() == Some(item) {
+                acc
+            } else {
+                last = Some(item.clone());
+                fold(acc, item)
             }
-        }
-        self.it.fold_mut(init, fold)
+        })
     }
 }
 
-/// A streaming iterator which only yields a limited number of elements in a streaming iterator.
+/// A streaming iterator which skips consecutive elements of a streaming iterator which a closure
+/// considers the same.
 #[derive(Clone, Debug)]
-pub struct Take<I> {
+pub struct DedupBy<I, F>
+where
+    I: StreamingIterator,
+    I::Item: Sized,
+{
     it: I,
-    n: usize,
-    done: bool,
+    same: F,
+    front: Option<I::Item>,
+    back: Option<I::Item>,
 }
 
-impl<I> StreamingIterator for Take<I>
+impl<I, F> StreamingIterator for DedupBy<I, F>
 where
     I: StreamingIterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> bool,
 {
     type Item = I::Item;
 
     #[inline]
     fn advance(&mut self) {
-        if self.n != 0 {
-            self.it.advance();
-            self.n -= 1;
-        } else {
-            self.done = true;
+        while let Some(item) = self.it.next() {
+            match self.front {
+                Some(ref last) if (self.same)(last, item) => {}
+                _ => {
+                    self.front = Some(item.clone());
+                    break;
+                }
+            }
         }
     }
 
     #[inline]
     fn is_done(&self) -> bool {
-        self.done || self.it.is_done()
+        self.it.is_done()
     }
 
     #[inline]
     fn get(&self) -> Option<&I::Item> {
-        if self.done {
-            None
-        } else {
-            self.it.get()
-        }
+        self.it.get()
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let hint = self.it.size_hint();
-        (cmp::min(hint.0, self.n), Some(self.n))
+        let started = self.front.is_some() || self.back.is_some();
+        dedup_size_hint(self.it.size_hint(), started)
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut last = self.front;
+        let mut same = self.same;
+        self.it.fold(init, move |acc, item| match last {
+            Some(ref last) if same(last, item) => acc,
+            _ => {
+                last = Some(item.clone());
+                fold(acc, item)
+            }
+        })
     }
 }
 
-impl<I> StreamingIteratorMut for Take<I>
+impl<I, F> DoubleEndedStreamingIterator for DedupBy<I, F>
 where
-    I: StreamingIteratorMut,
+    I: DoubleEndedStreamingIterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> bool,
 {
     #[inline]
-    fn get_mut(&mut self) -> Option<&mut I::Item> {
-        if self.done {
-            None
-        } else {
-            self.it.get_mut()
+    fn advance_back(&mut self) {
+        while let Some(item) = self.it.next_back() {
+            match self.back {
+                Some(ref last) if (self.same)(last, item) => {}
+                _ => {
+                    self.back = Some(item.clone());
+                    break;
+                }
+            }
         }
     }
+
+    #[inline]
+    fn rfold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut last = self.back;
+        let mut same = self.same;
+        self.it.rfold(init, move |acc, item| match last {
+            Some(ref last) if same(last, item) => acc,
+            _ => {
+                last = Some(item.clone());
+                fold(acc, item)
+            }
+        })
+    }
 }
 
-/// A streaming iterator which only returns initial elements matching a predicate.
-#[derive(Debug)]
-pub struct TakeWhile<I, F> {
+/// A streaming iterator which skips consecutive elements of a streaming iterator which map to the
+/// same key.
+#[derive(Clone, Debug)]
+pub struct DedupByKey<I, K, F> {
     it: I,
     f: F,
-    done: bool,
+    front: Option<K>,
+    back: Option<K>,
 }
 
-impl<I, F> StreamingIterator for TakeWhile<I, F>
+impl<I, K, F> StreamingIterator for DedupByKey<I, K, F>
 where
     I: StreamingIterator,
-    F: FnMut(&I::Item) -> bool,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
 {
     type Item = I::Item;
 
     #[inline]
     fn advance(&mut self) {
-        if !self.done {
-            self.it.advance();
-            if let Some(i) = self.it.get() {
-                if !(self.f)(i) {
-                    self.done = true;
-                }
+        while let Some(item) = self.it.next() {
+            let key = (self.f)(item);
+            if self.front.as_ref() != Some(&key) {
+                self.front = Some(key);
+                break;
             }
         }
     }
 
     #[inline]
     fn is_done(&self) -> bool {
-        self.done || self.it.is_done()
+        self.it.is_done()
     }
 
     #[inline]
     fn get(&self) -> Option<&I::Item> {
-        if self.done {
-            None
-        } else {
-            self.it.get()
-        }
+        self.it.get()
     }
 
     #[inline]
-    fn next(&mut self) -> Option<&I::Item> {
-        if self.done {
-            None
-        } else {
-            match self.it.next() {
-                Some(i) => {
-                    if (self.f)(i) {
-                        Some(i)
-                    } else {
-                        self.done = true;
-                        None
-                    }
-                }
-                None => None,
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let started = self.front.is_some() || self.back.is_some();
+        dedup_size_hint(self.it.size_hint(), started)
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut last = self.front;
+        let mut f = self.f;
+        self.it.fold(init, move |acc, item| {
+            let key = f(item);
+            if last.as_ref() == Some(&key) {
+                acc
+            } else {
+                last = Some(key);
+                fold(acc, item)
+            }
+        })
+    }
+}
+
+impl<I, K, F> DoubleEndedStreamingIterator for DedupByKey<I, K, F>
+where
+    I: DoubleEndedStreamingIterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    #[inline]
+    fn advance_back(&mut self) {
+        while let Some(item) = self.it.next_back() {
+            let key = (self.f)(item);
+            if self.back.as_ref() != Some(&key) {
+                self.back = Some(key);
+                break;
             }
         }
     }
 
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let upper = if self.done {
-            Some(0)
-        } else {
-            self.it.size_hint().1
-        };
-        (0, upper)
+    fn rfold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut last = self.back;
+        let mut f = self.f;
+        self.it.rfold(init, move |acc, item| {
+            let key = f(item);
+            if last.as_ref() == Some(&key) {
+                acc
+            } else {
+                last = Some(key);
+                fold(acc, item)
+            }
+        })
     }
 }
 
-impl<I, F> StreamingIteratorMut for TakeWhile<I, F>
+/// A normal, non-streaming, iterator which yields each run of equal elements of a streaming
+/// iterator along with its length.
+#[derive(Clone, Debug)]
+pub struct DistinctRuns<I>
 where
-    I: StreamingIteratorMut,
-    F: FnMut(&I::Item) -> bool,
+    I: StreamingIterator,
+    I::Item: Sized,
+{
+    it: I,
+    run: Option<(I::Item, usize)>,
+    done: bool,
+}
+
+impl<I> Iterator for DistinctRuns<I>
+where
+    I: StreamingIterator,
+    I::Item: PartialEq + Clone,
 {
+    type Item = (I::Item, usize);
+
     #[inline]
-    fn get_mut(&mut self) -> Option<&mut I::Item> {
+    fn next(&mut self) -> Option<(I::Item, usize)> {
         if self.done {
-            None
-        } else {
-            self.it.get_mut()
+            return None;
         }
+        let mut run = match self.run.take() {
+            Some(run) => run,
+            None => match self.it.next() {
+                Some(item) => (item.clone(), 1),
+                None => {
+                    self.done = true;
+                    return None;
+                }
+            },
+        };
+        loop {
+            match self.it.next() {
+                Some(item) if *item == run.0 => run.1 += 1,
+                Some(item) => {
+                    self.run = Some((item.clone(), 1));
+                    return Some(run);
+                }
+                None => {
+                    self.done = true;
+                    return Some(run);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let pending = self.run.is_some() as usize;
+        let (lower, upper) = self.it.size_hint();
+        let lower = cmp::min(lower.saturating_add(pending), 1);
+        (lower, upper.and_then(|n| n.checked_add(pending)))
     }
 }
 
-/// A streaming iterator which returns elements in the opposite order.
-pub struct Rev<I>(I);
+/// A streaming iterator which tracks the index of the current element of a streaming iterator.
+#[derive(Clone, Debug)]
+pub struct Enumerate<I> {
+    it: I,
+    count: usize,
+}
 
-impl<I> StreamingIterator for Rev<I>
+impl<I> Enumerate<I> {
+    /// Returns the zero-based index of the current element.
+    ///
+    /// Before the first element has been reached, this returns 0. Once the iterator is
+    /// exhausted, this returns the index of the last element.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.count.saturating_sub(1)
+    }
+}
+
+impl<I> StreamingIterator for Enumerate<I>
 where
-    I: DoubleEndedStreamingIterator,
+    I: StreamingIterator,
 {
     type Item = I::Item;
 
     #[inline]
     fn advance(&mut self) {
-        self.0.advance_back();
+        self.it.advance();
+        if !self.it.is_done() {
+            self.count += 1;
+        }
     }
 
     #[inline]
     fn is_done(&self) -> bool {
-        self.0.is_done()
+        self.it.is_done()
     }
 
     #[inline]
     fn get(&self) -> Option<&I::Item> {
-        self.0.get()
+        self.it.get()
     }
 
     #[inline]
-    fn next(&mut self) -> Option<&I::Item> {
-        self.0.next_back()
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
     }
 
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
+    fn count(self) -> usize {
+        self.it.count()
     }
 
     #[inline]
@@ -2406,568 +3657,6253 @@ where
         Self: Sized,
         Fold: FnMut(Acc, &Self::Item) -> Acc,
     {
-        self.0.rfold(init, f)
+        self.it.fold(init, f)
     }
 }
 
-impl<I> DoubleEndedStreamingIterator for Rev<I>
+impl<I> StreamingIteratorMut for Enumerate<I>
 where
-    I: DoubleEndedStreamingIterator,
+    I: StreamingIteratorMut,
 {
     #[inline]
-    fn advance_back(&mut self) {
-        self.0.advance();
-    }
-
-    #[inline]
-    fn next_back(&mut self) -> Option<&I::Item> {
-        self.0.next()
+    fn get_mut(&mut self) -> Option<&mut I::Item> {
+        self.it.get_mut()
     }
 
     #[inline]
-    fn rfold<Acc, Fold>(self, init: Acc, f: Fold) -> Acc
+    fn fold_mut<Acc, Fold>(self, init: Acc, f: Fold) -> Acc
     where
         Self: Sized,
-        Fold: FnMut(Acc, &Self::Item) -> Acc,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
     {
-        self.0.fold(init, f)
+        self.it.fold_mut(init, f)
     }
 }
 
-impl<I> StreamingIteratorMut for Rev<I>
+/// A streaming iterator which filters the elements of a streaming iterator with a predicate.
+#[derive(Clone, Debug)]
+pub struct Filter<I, F> {
+    it: I,
+    f: F,
+    done: bool,
+}
+
+impl<I, F> StreamingIterator for Filter<I, F>
 where
-    I: DoubleEndedStreamingIteratorMut,
+    I: StreamingIterator,
+    F: FnMut(&I::Item) -> bool,
 {
+    type Item = I::Item;
+
     #[inline]
-    fn get_mut(&mut self) -> Option<&mut I::Item> {
-        self.0.get_mut()
+    fn advance(&mut self) {
+        self.done = true;
+        while let Some(i) = self.it.next() {
+            if (self.f)(i) {
+                self.done = false;
+                break;
+            }
+        }
     }
 
     #[inline]
-    fn fold_mut<B, F>(self, init: B, f: F) -> B
-    where
-        Self: Sized,
-        F: FnMut(B, &mut Self::Item) -> B,
-    {
-        self.0.rfold_mut(init, f)
+    fn is_done(&self) -> bool {
+        self.done
     }
-}
 
-impl<I> DoubleEndedStreamingIteratorMut for Rev<I>
-where
-    I: DoubleEndedStreamingIteratorMut,
-{
     #[inline]
-    fn rfold_mut<B, F>(self, init: B, f: F) -> B
-    where
-        Self: Sized,
-        F: FnMut(B, &mut Self::Item) -> B,
-    {
-        self.0.fold_mut(init, f)
+    fn get(&self) -> Option<&I::Item> {
+        if self.done {
+            None
+        } else {
+            self.it.get()
+        }
     }
-}
 
-/// Conversion from [`IntoIterator`] to [`StreamingIterator`].
-pub trait IntoStreamingIterator: IntoIterator
-where
-    Self: Sized,
-{
-    /// Turns an [`IntoIterator`] into a [`StreamingIterator`].
-    ///
-    /// Calling this method on an [`IntoIterator`] is equivalent to using [`convert`].
     #[inline]
-    fn into_streaming_iter(self) -> Convert<Self::IntoIter> {
-        convert(self)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.it.size_hint().1)
     }
 
-    /// Turns an [`IntoIterator`] of references into a [`StreamingIterator`].
-    ///
-    /// Calling this method on an [`IntoIterator`] is equivalent to using [`convert_ref`].
     #[inline]
-    fn into_streaming_iter_ref<'a, T: ?Sized>(self) -> ConvertRef<'a, Self::IntoIter, T>
+    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
     where
-        Self: IntoIterator<Item = &'a T>,
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
     {
-        convert_ref(self)
+        let mut f = self.f;
+        self.it.fold(
+            init,
+            move |acc, item| {
+                if f(item) {
+                    fold(acc, item)
+                } else {
+                    acc
+                }
+            },
+        )
     }
+}
 
-    /// Turns an [`IntoIterator`] of mutable references into a [`StreamingIteratorMut`].
-    ///
-    /// Calling this method on an [`IntoIterator`] is equivalent to using [`convert_mut`].
+impl<I, F> DoubleEndedStreamingIterator for Filter<I, F>
+where
+    I: DoubleEndedStreamingIterator,
+    F: FnMut(&I::Item) -> bool,
+{
     #[inline]
-    fn into_streaming_iter_mut<'a, T: ?Sized>(self) -> ConvertMut<'a, Self::IntoIter, T>
+    fn advance_back(&mut self) {
+        self.done = true;
+        while let Some(i) = self.it.next_back() {
+            if (self.f)(i) {
+                self.done = false;
+                break;
+            }
+        }
+    }
+
+    #[inline]
+    fn rfold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
     where
-        Self: IntoIterator<Item = &'a mut T>,
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
     {
-        convert_mut(self)
+        let mut f = self.f;
+        self.it.rfold(
+            init,
+            move |acc, item| {
+                if f(item) {
+                    fold(acc, item)
+                } else {
+                    acc
+                }
+            },
+        )
     }
 }
 
-impl<I> IntoStreamingIterator for I where I: IntoIterator {}
+impl<I, F> StreamingIteratorMut for Filter<I, F>
+where
+    I: StreamingIteratorMut,
+    F: FnMut(&I::Item) -> bool,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut I::Item> {
+        if self.done {
+            None
+        } else {
+            self.it.get_mut()
+        }
+    }
 
-#[cfg(test)]
-mod test {
-    use core::fmt::Debug;
+    #[inline]
+    fn fold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.it.fold_mut(
+            init,
+            move |acc, item| {
+                if f(&*item) {
+                    fold(acc, item)
+                } else {
+                    acc
+                }
+            },
+        )
+    }
+}
 
-    #[cfg(feature = "alloc")]
-    use alloc::vec::Vec;
+impl<I, F> DoubleEndedStreamingIteratorMut for Filter<I, F>
+where
+    I: DoubleEndedStreamingIteratorMut,
+    F: FnMut(&I::Item) -> bool,
+{
+    #[inline]
+    fn rfold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.it.rfold_mut(
+            init,
+            move |acc, item| {
+                if f(&*item) {
+                    fold(acc, item)
+                } else {
+                    acc
+                }
+            },
+        )
+    }
+}
 
-    use super::*;
+/// An iterator which both filters and maps elements of a streaming iterator with a closure.
+///
+/// The mapped values are owned by the iterator, so `StreamingIteratorMut` is implemented even if
+/// the underlying iterator is not mutable. Changes made through `get_mut`, `next_mut` or
+/// `fold_mut` only affect the mapped value, and are discarded when the iterator advances.
+#[derive(Clone, Debug)]
+pub struct FilterMap<I, B, F> {
+    it: I,
+    f: F,
+    item: Option<B>,
+}
 
-    fn test<I>(mut it: I, expected: &[I::Item])
+impl<I, B, F> StreamingIterator for FilterMap<I, B, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&I::Item) -> Option<B>,
+{
+    type Item = B;
+
+    #[inline]
+    fn advance(&mut self) {
+        loop {
+            match self.it.next() {
+                Some(i) => {
+                    if let Some(i) = (self.f)(i) {
+                        self.item = Some(i);
+                        break;
+                    }
+                }
+                None => {
+                    self.item = None;
+                    break;
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&B> {
+        self.item.as_ref()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.it.size_hint().1)
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
     where
-        I: StreamingIterator,
-        I::Item: Sized + PartialEq + Debug,
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
     {
-        for item in expected {
-            it.advance();
-            assert_eq!(it.get(), Some(item));
-            assert_eq!(it.get(), Some(item));
+        let mut f = self.f;
+        self.it.fold(init, move |acc, item| match f(item) {
+            Some(item) => fold(acc, &item),
+            None => acc,
+        })
+    }
+}
+
+impl<I, B, F> DoubleEndedStreamingIterator for FilterMap<I, B, F>
+where
+    I: DoubleEndedStreamingIterator,
+    F: FnMut(&I::Item) -> Option<B>,
+{
+    #[inline]
+    fn advance_back(&mut self) {
+        loop {
+            match self.it.next_back() {
+                Some(i) => {
+                    if let Some(i) = (self.f)(i) {
+                        self.item = Some(i);
+                        break;
+                    }
+                }
+                None => {
+                    self.item = None;
+                    break;
+                }
+            }
         }
-        it.advance();
-        assert_eq!(it.get(), None);
+    }
+
+    #[inline]
+    fn rfold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.it.rfold(init, move |acc, item| match f(item) {
+            Some(item) => fold(acc, &item),
+            None => acc,
+        })
+    }
+}
+
+impl<I, B, F> StreamingIteratorMut for FilterMap<I, B, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&I::Item) -> Option<B>,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut B> {
+        self.item.as_mut()
+    }
+
+    #[inline]
+    fn fold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.it.fold(init, move |acc, item| match f(item) {
+            Some(mut item) => fold(acc, &mut item),
+            None => acc,
+        })
+    }
+}
+
+impl<I, B, F> DoubleEndedStreamingIteratorMut for FilterMap<I, B, F>
+where
+    I: DoubleEndedStreamingIterator,
+    F: FnMut(&I::Item) -> Option<B>,
+{
+    #[inline]
+    fn rfold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.it.rfold(init, move |acc, item| match f(item) {
+            Some(mut item) => fold(acc, &mut item),
+            None => acc,
+        })
+    }
+}
+
+/// A streaming iterator that maps elements to iterators with a closure and then yields the
+/// concatenation of the obtained iterators
+#[derive(Clone, Debug)]
+pub struct FlatMap<I, J, F> {
+    it: I,
+    f: F,
+    // The sub-iterator being consumed from the front.
+    sub_iter: Option<J>,
+    // The sub-iterator being consumed from the back.
+    back_iter: Option<J>,
+    // Whether the current element comes from `back_iter` rather than `sub_iter`.
+    at_back: bool,
+}
+
+impl<I, J, F> FlatMap<I, J, F> {
+    #[inline]
+    fn current(&self) -> Option<&J> {
+        if self.at_back {
+            self.back_iter.as_ref()
+        } else {
+            self.sub_iter.as_ref()
+        }
+    }
+
+    #[inline]
+    fn current_mut(&mut self) -> Option<&mut J> {
+        if self.at_back {
+            self.back_iter.as_mut()
+        } else {
+            self.sub_iter.as_mut()
+        }
+    }
+}
+
+impl<I, J, F> StreamingIterator for FlatMap<I, J, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&I::Item) -> J,
+    J: StreamingIterator,
+{
+    type Item = J::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.at_back = false;
+        loop {
+            if let Some(ref mut iter) = self.sub_iter {
+                iter.advance();
+                if !iter.is_done() {
+                    return;
+                }
+            }
+            if let Some(item) = self.it.next() {
+                self.sub_iter = Some((self.f)(item));
+            } else {
+                break;
+            }
+        }
+        // Once the outer iterator is exhausted, the remaining elements are those of the
+        // sub-iterator that was started from the back.
+        if let Some(mut iter) = self.back_iter.take() {
+            iter.advance();
+            self.sub_iter = Some(iter);
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        match self.current() {
+            Some(iter) => iter.is_done(),
+            None => true,
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&Self::Item> {
+        self.current().and_then(J::get)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (front_lower, front_upper) = self.sub_iter.as_ref().map_or((0, Some(0)), J::size_hint);
+        let (back_lower, back_upper) = self.back_iter.as_ref().map_or((0, Some(0)), J::size_hint);
+        let lower = front_lower.saturating_add(back_lower);
+        // Elements of the outer iterator which haven't been reached yet may each map to any number
+        // of elements.
+        let upper = match (self.it.size_hint(), front_upper, back_upper) {
+            ((_, Some(0)), Some(front), Some(back)) => front.checked_add(back),
+            _ => None,
+        };
+        (lower, upper)
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        let mut count = self.sub_iter.map_or(0, J::count);
+        count += self.back_iter.map_or(0, J::count);
+        let mut f = self.f;
+        self.it.fold(count, |count, item| count + f(item).count())
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if let Some(iter) = self.sub_iter {
+            acc = iter.fold(acc, &mut fold);
+        }
+        let mut f = self.f;
+        acc = self.it.fold(acc, |acc, item| f(item).fold(acc, &mut fold));
+        if let Some(iter) = self.back_iter {
+            acc = iter.fold(acc, &mut fold);
+        }
+        acc
+    }
+}
+
+impl<I, J, F> DoubleEndedStreamingIterator for FlatMap<I, J, F>
+where
+    I: DoubleEndedStreamingIterator,
+    F: FnMut(&I::Item) -> J,
+    J: DoubleEndedStreamingIterator,
+{
+    #[inline]
+    fn advance_back(&mut self) {
+        self.at_back = true;
+        loop {
+            if let Some(ref mut iter) = self.back_iter {
+                iter.advance_back();
+                if !iter.is_done() {
+                    return;
+                }
+            }
+            if let Some(item) = self.it.next_back() {
+                self.back_iter = Some((self.f)(item));
+            } else {
+                break;
+            }
+        }
+        if let Some(mut iter) = self.sub_iter.take() {
+            iter.advance_back();
+            self.back_iter = Some(iter);
+        }
+    }
+
+    #[inline]
+    fn rfold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if let Some(iter) = self.back_iter {
+            acc = iter.rfold(acc, &mut fold);
+        }
+        let mut f = self.f;
+        acc = self
+            .it
+            .rfold(acc, |acc, item| f(item).rfold(acc, &mut fold));
+        if let Some(iter) = self.sub_iter {
+            acc = iter.rfold(acc, &mut fold);
+        }
+        acc
+    }
+}
+
+impl<I, J, F> StreamingIteratorMut for FlatMap<I, J, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&I::Item) -> J,
+    J: StreamingIteratorMut,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.current_mut().and_then(J::get_mut)
+    }
+
+    #[inline]
+    fn fold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if let Some(iter) = self.sub_iter {
+            acc = iter.fold_mut(acc, &mut fold);
+        }
+        let mut f = self.f;
+        acc = self
+            .it
+            .fold(acc, |acc, item| f(item).fold_mut(acc, &mut fold));
+        if let Some(iter) = self.back_iter {
+            acc = iter.fold_mut(acc, &mut fold);
+        }
+        acc
+    }
+}
+
+impl<I, J, F> DoubleEndedStreamingIteratorMut for FlatMap<I, J, F>
+where
+    I: DoubleEndedStreamingIterator,
+    F: FnMut(&I::Item) -> J,
+    J: DoubleEndedStreamingIteratorMut,
+{
+    #[inline]
+    fn rfold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if let Some(iter) = self.back_iter {
+            acc = iter.rfold_mut(acc, &mut fold);
+        }
+        let mut f = self.f;
+        acc = self
+            .it
+            .rfold(acc, |acc, item| f(item).rfold_mut(acc, &mut fold));
+        if let Some(iter) = self.sub_iter {
+            acc = iter.rfold_mut(acc, &mut fold);
+        }
+        acc
+    }
+}
+
+/// A streaming iterator that maps mutable elements to mutable iterators with a closure and then
+/// yields the concatenation of the obtained iterators
+#[derive(Clone, Debug)]
+pub struct FlatMapMut<I, J, F> {
+    it: I,
+    f: F,
+    sub_iter: Option<J>,
+}
+
+impl<I, J, F> StreamingIterator for FlatMapMut<I, J, F>
+where
+    I: StreamingIteratorMut,
+    F: FnMut(&mut I::Item) -> J,
+    J: StreamingIteratorMut,
+{
+    type Item = J::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        loop {
+            if let Some(ref mut iter) = self.sub_iter {
+                iter.advance();
+                if !iter.is_done() {
+                    break;
+                }
+            }
+            if let Some(item) = self.it.next_mut() {
+                self.sub_iter = Some((self.f)(item));
+            } else {
+                break;
+            }
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        match self.sub_iter {
+            Some(ref iter) => iter.is_done(),
+            None => true,
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&Self::Item> {
+        self.sub_iter.as_ref().and_then(J::get)
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if let Some(iter) = self.sub_iter {
+            acc = iter.fold(acc, &mut fold);
+        }
+        let mut f = self.f;
+        self.it
+            .fold_mut(acc, |acc, item| f(item).fold(acc, &mut fold))
+    }
+}
+
+impl<I, J, F> StreamingIteratorMut for FlatMapMut<I, J, F>
+where
+    I: StreamingIteratorMut,
+    F: FnMut(&mut I::Item) -> J,
+    J: StreamingIteratorMut,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.sub_iter.as_mut().and_then(J::get_mut)
+    }
+
+    #[inline]
+    fn fold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if let Some(iter) = self.sub_iter {
+            acc = iter.fold_mut(acc, &mut fold);
+        }
+        let mut f = self.f;
+        self.it
+            .fold_mut(acc, |acc, item| f(item).fold_mut(acc, &mut fold))
+    }
+}
+
+/// A streaming iterator that flattens slices borrowed from the elements of a streaming iterator.
+#[derive(Clone, Debug)]
+pub struct FlatMapRef<I, F> {
+    it: I,
+    f: F,
+    index: Option<usize>,
+}
+
+impl<I, B, F> StreamingIterator for FlatMapRef<I, F>
+where
+    I: StreamingIterator,
+    F: Fn(&I::Item) -> &[B],
+{
+    type Item = B;
+
+    #[inline]
+    fn advance(&mut self) {
+        let mut next = match self.index {
+            Some(index) => index + 1,
+            None => {
+                self.it.advance();
+                0
+            }
+        };
+        loop {
+            match self.it.get() {
+                Some(item) if next < (self.f)(item).len() => {
+                    self.index = Some(next);
+                    return;
+                }
+                Some(_) => {
+                    self.it.advance();
+                    next = 0;
+                }
+                None => {
+                    self.index = None;
+                    return;
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&B> {
+        let index = self.index?;
+        (self.f)(self.it.get()?).get(index)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = match (self.index, self.it.get()) {
+            (Some(index), Some(item)) => (self.f)(item).len() - index - 1,
+            _ => 0,
+        };
+        match self.it.size_hint() {
+            (_, Some(0)) => (lower, Some(lower)),
+            _ => (lower, None),
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        let f = self.f;
+        if let (Some(index), Some(item)) = (self.index, self.it.get()) {
+            acc = f(item)[index + 1..].iter().fold(acc, &mut fold);
+        }
+        self.it
+            .fold(acc, |acc, item| f(item).iter().fold(acc, &mut fold))
+    }
+}
+
+/// A streaming iterator that flattens nested streaming iterators.
+#[derive(Clone, Debug)]
+pub struct Flatten<I> {
+    iter: I,
+    first: bool,
+}
+
+impl<I> StreamingIterator for Flatten<I>
+where
+    I: StreamingIteratorMut,
+    I::Item: StreamingIterator,
+{
+    type Item = <I::Item as StreamingIterator>::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        if self.first {
+            self.first = false;
+            self.iter.advance();
+        }
+        while let Some(iter) = self.iter.get_mut() {
+            iter.advance();
+            if !iter.is_done() {
+                break;
+            }
+            self.iter.advance(); // since we got Some, self.iter is not done and can be advanced
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        match self.iter.get() {
+            Some(iter) => iter.is_done(),
+            None => true,
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&Self::Item> {
+        self.iter.get().and_then(I::Item::get)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = match self.iter.get() {
+            Some(iter) if !self.first => iter.size_hint(),
+            _ => (0, Some(0)),
+        };
+        // Elements of the outer iterator which haven't been reached yet may each hold any number
+        // of elements.
+        match self.iter.size_hint() {
+            (_, Some(0)) => (lower, upper),
+            _ => (lower, None),
+        }
+    }
+
+    #[inline]
+    fn count(mut self) -> usize {
+        let mut count = 0;
+        if !self.first {
+            if let Some(iter) = self.iter.get_mut() {
+                count = iter.count();
+            }
+        }
+        self.iter
+            .fold_mut(count, |count, item| count + item.count())
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(mut self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if !self.first {
+            if let Some(iter) = self.iter.get_mut() {
+                acc = iter.fold(acc, &mut fold);
+            }
+        }
+        self.iter
+            .fold_mut(acc, |acc, item| item.fold(acc, &mut fold))
+    }
+}
+
+impl<I> StreamingIteratorMut for Flatten<I>
+where
+    I: StreamingIteratorMut,
+    I::Item: StreamingIteratorMut,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.iter.get_mut().and_then(I::Item::get_mut)
+    }
+
+    #[inline]
+    fn fold_mut<Acc, Fold>(mut self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if !self.first {
+            if let Some(iter) = self.iter.get_mut() {
+                acc = iter.fold_mut(acc, &mut fold);
+            }
+        }
+        self.iter
+            .fold_mut(acc, |acc, item| item.fold_mut(acc, &mut fold))
+    }
+}
+
+/// A streaming iterator that flattens nested streaming iterators and tracks the index of the
+/// outer element.
+#[derive(Clone, Debug)]
+pub struct FlattenIndexed<I> {
+    iter: I,
+    first: bool,
+    outer: usize,
+}
+
+impl<I> FlattenIndexed<I>
+where
+    I: StreamingIteratorMut,
+    I::Item: StreamingIterator,
+{
+    /// Returns the index of the outer element the current element came from.
+    #[inline]
+    pub fn outer_index(&self) -> Option<usize> {
+        if self.is_done() {
+            None
+        } else {
+            Some(self.outer - 1)
+        }
+    }
+
+    /// Returns the current element together with the index of the outer element it came from.
+    #[inline]
+    pub fn get_indexed(&self) -> Option<(usize, &<I::Item as StreamingIterator>::Item)> {
+        let item = self.get()?;
+        Some((self.outer - 1, item))
+    }
+
+    /// Advances the iterator and returns the next element together with the index of the outer
+    /// element it came from.
+    #[inline]
+    pub fn next_indexed(&mut self) -> Option<(usize, &<I::Item as StreamingIterator>::Item)> {
+        self.advance();
+        self.get_indexed()
+    }
+}
+
+impl<I> StreamingIterator for FlattenIndexed<I>
+where
+    I: StreamingIteratorMut,
+    I::Item: StreamingIterator,
+{
+    type Item = <I::Item as StreamingIterator>::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        if self.first {
+            self.first = false;
+            self.iter.advance();
+            self.outer += 1;
+        }
+        while let Some(iter) = self.iter.get_mut() {
+            iter.advance();
+            if !iter.is_done() {
+                break;
+            }
+            self.iter.advance();
+            self.outer += 1;
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        match self.iter.get() {
+            Some(iter) => iter.is_done(),
+            None => true,
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&Self::Item> {
+        self.iter.get().and_then(I::Item::get)
+    }
+}
+
+impl<I> StreamingIteratorMut for FlattenIndexed<I>
+where
+    I: StreamingIteratorMut,
+    I::Item: StreamingIteratorMut,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.iter.get_mut().and_then(I::Item::get_mut)
+    }
+}
+
+/// A regular, non-streaming iterator which both filters and maps elements of a streaming iterator with a closure.
+#[derive(Clone, Debug)]
+pub struct FilterMapDeref<I, F> {
+    it: I,
+    f: F,
+}
+
+impl<I, B, F> Iterator for FilterMapDeref<I, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&I::Item) -> Option<B>,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(item) = self.it.next() {
+            if let Some(mapped) = (self.f)(item) {
+                return Some(mapped);
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut map = self.f;
+        self.it.fold(init, move |acc, item| match map(item) {
+            Some(mapped) => f(acc, mapped),
+            None => acc,
+        })
+    }
+}
+
+impl<I, B, F> DoubleEndedIterator for FilterMapDeref<I, F>
+where
+    I: DoubleEndedStreamingIterator,
+    F: FnMut(&I::Item) -> Option<B>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<B> {
+        while let Some(item) = self.it.next_back() {
+            if let Some(mapped) = (self.f)(item) {
+                return Some(mapped);
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn rfold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut map = self.f;
+        self.it.rfold(init, move |acc, item| match map(item) {
+            Some(mapped) => f(acc, mapped),
+            None => acc,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum FuseState {
+    Start,
+    Middle,
+    End,
+}
+
+/// A streaming iterator which is well-defined before and after iteration.
+#[derive(Clone, Debug)]
+pub struct Fuse<I> {
+    it: I,
+    state: FuseState,
+}
+
+impl<I> StreamingIterator for Fuse<I>
+where
+    I: StreamingIterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        match self.state {
+            FuseState::Start => {
+                self.it.advance();
+                self.state = if self.it.is_done() {
+                    FuseState::End
+                } else {
+                    FuseState::Middle
+                };
+            }
+            FuseState::Middle => {
+                self.it.advance();
+                if self.it.is_done() {
+                    self.state = FuseState::End;
+                }
+            }
+            FuseState::End => {}
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        match self.state {
+            FuseState::Start | FuseState::End => true,
+            FuseState::Middle => false,
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&I::Item> {
+        match self.state {
+            FuseState::Start | FuseState::End => None,
+            FuseState::Middle => self.it.get(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<&I::Item> {
+        match self.state {
+            FuseState::Start => match self.it.next() {
+                Some(i) => {
+                    self.state = FuseState::Middle;
+                    Some(i)
+                }
+                None => {
+                    self.state = FuseState::End;
+                    None
+                }
+            },
+            FuseState::Middle => match self.it.next() {
+                Some(i) => Some(i),
+                None => {
+                    self.state = FuseState::End;
+                    None
+                }
+            },
+            FuseState::End => None,
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        match self.state {
+            FuseState::Start | FuseState::Middle => self.it.count(),
+            FuseState::End => 0,
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        match self.state {
+            FuseState::Start | FuseState::Middle => self.it.fold(init, fold),
+            FuseState::End => init,
+        }
+    }
+}
+
+impl<I> StreamingIteratorMut for Fuse<I>
+where
+    I: StreamingIteratorMut,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut I::Item> {
+        match self.state {
+            FuseState::Start | FuseState::End => None,
+            FuseState::Middle => self.it.get_mut(),
+        }
+    }
+
+    #[inline]
+    fn fold_mut<Acc, Fold>(self, init: Acc, fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        match self.state {
+            FuseState::Start | FuseState::Middle => self.it.fold_mut(init, fold),
+            FuseState::End => init,
+        }
+    }
+}
+
+/// A streaming iterator which alternates between the elements of two streaming iterators.
+#[derive(Clone, Debug)]
+pub struct Interleave<A, B> {
+    a: A,
+    b: B,
+    a_done: bool,
+    b_done: bool,
+    state: MergeState,
+}
+
+impl<A, B> StreamingIterator for Interleave<A, B>
+where
+    A: StreamingIterator,
+    B: StreamingIterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        // It's `b`'s turn only if `a` produced the current element.
+        let mut from_a = !matches!(self.state, MergeState::A);
+        for _ in 0..2 {
+            if from_a && !self.a_done {
+                self.a.advance();
+                if self.a.get().is_some() {
+                    self.state = MergeState::A;
+                    return;
+                }
+                self.a_done = true;
+            } else if !from_a && !self.b_done {
+                self.b.advance();
+                if self.b.get().is_some() {
+                    self.state = MergeState::B;
+                    return;
+                }
+                self.b_done = true;
+            }
+            from_a = !from_a;
+        }
+        self.state = MergeState::Done;
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&A::Item> {
+        self.state.get(&self.a, &self.b)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if let MergeState::Done = self.state {
+            return (0, Some(0));
+        }
+        let (a_lower, a_upper) = if self.a_done {
+            (0, Some(0))
+        } else {
+            self.a.size_hint()
+        };
+        let (b_lower, b_upper) = if self.b_done {
+            (0, Some(0))
+        } else {
+            self.b.size_hint()
+        };
+        let lower = a_lower.saturating_add(b_lower);
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+/// A streaming iterator that calls a function with element before yielding it.
+#[derive(Clone, Debug)]
+pub struct Inspect<I, F> {
+    it: I,
+    f: F,
+}
+
+impl<I, F> StreamingIterator for Inspect<I, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&I::Item),
+{
+    type Item = I::Item;
+
+    fn advance(&mut self) {
+        if let Some(item) = self.it.next() {
+            (self.f)(item);
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.it.is_done()
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.it.get()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.it.fold(init, |acc, item| {
+            f(item);
+            fold(acc, item)
+        })
+    }
+}
+
+impl<I, F> DoubleEndedStreamingIterator for Inspect<I, F>
+where
+    I: DoubleEndedStreamingIterator,
+    F: FnMut(&I::Item),
+{
+    fn advance_back(&mut self) {
+        if let Some(item) = self.it.next_back() {
+            (self.f)(item);
+        }
+    }
+
+    #[inline]
+    fn rfold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.it.rfold(init, |acc, item| {
+            f(item);
+            fold(acc, item)
+        })
+    }
+}
+
+impl<I, F> StreamingIteratorMut for Inspect<I, F>
+where
+    I: StreamingIteratorMut,
+    F: FnMut(&I::Item),
+{
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.it.get_mut()
+    }
+
+    #[inline]
+    fn fold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.it.fold_mut(init, |acc, item| {
+            f(&*item);
+            fold(acc, item)
+        })
+    }
+}
+
+impl<I, F> DoubleEndedStreamingIteratorMut for Inspect<I, F>
+where
+    I: DoubleEndedStreamingIteratorMut,
+    F: FnMut(&I::Item),
+{
+    #[inline]
+    fn rfold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.it.rfold_mut(init, |acc, item| {
+            f(&*item);
+            fold(acc, item)
+        })
+    }
+}
+
+/// A streaming iterator which pairs each element of a streaming iterator with the element
+/// following it.
+#[derive(Clone, Debug)]
+pub struct Lookahead<I>
+where
+    I: StreamingIterator,
+    I::Item: Sized,
+{
+    it: I,
+    item: Option<(I::Item, Option<I::Item>)>,
+    first: bool,
+}
+
+impl<I> StreamingIterator for Lookahead<I>
+where
+    I: StreamingIterator,
+    I::Item: Clone,
+{
+    type Item = (I::Item, Option<I::Item>);
+
+    #[inline]
+    fn advance(&mut self) {
+        let current = if self.first {
+            self.first = false;
+            self.it.next().cloned()
+        } else {
+            self.item.take().and_then(|(_, next)| next)
+        };
+        self.item = current.map(|current| (current, self.it.next().cloned()));
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&Self::Item> {
+        self.item.as_ref()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.first {
+            return self.it.size_hint();
+        }
+        match self.item {
+            Some((_, Some(_))) => {
+                let (lower, upper) = self.it.size_hint();
+                (
+                    lower.saturating_add(1),
+                    upper.and_then(|upper| upper.checked_add(1)),
+                )
+            }
+            _ => (0, Some(0)),
+        }
+    }
+}
+
+impl<I> StreamingIteratorMut for Lookahead<I>
+where
+    I: StreamingIterator,
+    I::Item: Clone,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.item.as_mut()
+    }
+}
+
+/// A streaming iterator which transforms the elements of a streaming iterator.
+#[derive(Clone, Debug)]
+pub struct Map<I, B, F> {
+    it: I,
+    f: F,
+    item: Option<B>,
+}
+
+impl<I, B, F> StreamingIterator for Map<I, B, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.item = self.it.next().map(&mut self.f);
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&B> {
+        self.item.as_ref()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.it.fold(init, move |acc, item| fold(acc, &f(item)))
+    }
+}
+
+impl<I, B, F> DoubleEndedStreamingIterator for Map<I, B, F>
+where
+    I: DoubleEndedStreamingIterator,
+    F: FnMut(&I::Item) -> B,
+{
+    #[inline]
+    fn advance_back(&mut self) {
+        self.item = self.it.next_back().map(&mut self.f);
+    }
+
+    #[inline]
+    fn rfold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.it.rfold(init, move |acc, item| fold(acc, &f(item)))
+    }
+}
+
+impl<I, B, F> StreamingIteratorMut for Map<I, B, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&I::Item) -> B,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut B> {
+        self.item.as_mut()
+    }
+
+    #[inline]
+    fn fold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.it.fold(init, move |acc, item| fold(acc, &mut f(item)))
+    }
+}
+
+impl<I, B, F> DoubleEndedStreamingIteratorMut for Map<I, B, F>
+where
+    I: DoubleEndedStreamingIterator,
+    F: FnMut(&I::Item) -> B,
+{
+    #[inline]
+    fn rfold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.it
+            .rfold(init, move |acc, item| fold(acc, &mut f(item)))
+    }
+}
+
+/// A streaming iterator which merges two sorted streaming iterators.
+#[derive(Clone, Debug)]
+pub struct Merge<A, B> {
+    a: A,
+    b: B,
+    state: MergeState,
+}
+
+/// A streaming iterator which merges two streaming iterators with a closure deciding which
+/// element comes first.
+#[derive(Clone, Debug)]
+pub struct MergeBy<A, B, F> {
+    a: A,
+    b: B,
+    state: MergeState,
+    first: F,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum MergeState {
+    // Neither iterator has been advanced yet
+    Init,
+    // The current element comes from the first iterator
+    A,
+    // The current element comes from the second iterator
+    B,
+    // Both iterators are exhausted
+    Done,
+}
+
+impl MergeState {
+    #[inline]
+    fn advance<A, B, F>(&mut self, a: &mut A, b: &mut B, mut first: F)
+    where
+        A: StreamingIterator,
+        B: StreamingIterator<Item = A::Item>,
+        F: FnMut(&A::Item, &A::Item) -> bool,
+    {
+        match *self {
+            MergeState::Init => {
+                a.advance();
+                b.advance();
+            }
+            MergeState::A => a.advance(),
+            MergeState::B => b.advance(),
+            MergeState::Done => return,
+        }
+        *self = match (a.get(), b.get()) {
+            (Some(x), Some(y)) => {
+                if first(x, y) {
+                    MergeState::A
+                } else {
+                    MergeState::B
+                }
+            }
+            (Some(_), None) => MergeState::A,
+            (None, Some(_)) => MergeState::B,
+            (None, None) => MergeState::Done,
+        };
+    }
+
+    #[inline]
+    fn get<'a, A, B>(&self, a: &'a A, b: &'a B) -> Option<&'a A::Item>
+    where
+        A: StreamingIterator,
+        B: StreamingIterator<Item = A::Item>,
+    {
+        match *self {
+            MergeState::A => a.get(),
+            MergeState::B => b.get(),
+            MergeState::Init | MergeState::Done => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint<A, B>(&self, a: &A, b: &B) -> (usize, Option<usize>)
+    where
+        A: StreamingIterator,
+        B: StreamingIterator<Item = A::Item>,
+    {
+        // The current element of the side which was not yielded is still pending.
+        let pending = match *self {
+            MergeState::Init => 0,
+            MergeState::A => !b.is_done() as usize,
+            MergeState::B => !a.is_done() as usize,
+            MergeState::Done => return (0, Some(0)),
+        };
+        let (a_lower, a_upper) = a.size_hint();
+        let (b_lower, b_upper) = b.size_hint();
+        let lower = a_lower.saturating_add(b_lower).saturating_add(pending);
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => x.checked_add(y).and_then(|n| n.checked_add(pending)),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<A, B> StreamingIterator for Merge<A, B>
+where
+    A: StreamingIterator,
+    B: StreamingIterator<Item = A::Item>,
+    A::Item: Ord,
+{
+    type Item = A::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.state.advance(&mut self.a, &mut self.b, |x, y| x <= y);
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&A::Item> {
+        self.state.get(&self.a, &self.b)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint(&self.a, &self.b)
+    }
+}
+
+impl<A, B, F> StreamingIterator for MergeBy<A, B, F>
+where
+    A: StreamingIterator,
+    B: StreamingIterator<Item = A::Item>,
+    F: FnMut(&A::Item, &A::Item) -> bool,
+{
+    type Item = A::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.state
+            .advance(&mut self.a, &mut self.b, &mut self.first);
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&A::Item> {
+        self.state.get(&self.a, &self.b)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint(&self.a, &self.b)
+    }
+}
+
+/// A streaming iterator which transforms the elements of a streaming iterator until the closure
+/// returns `None`.
+#[derive(Clone, Debug)]
+pub struct MapWhile<I, B, F> {
+    it: I,
+    f: F,
+    item: Option<B>,
+    done: bool,
+}
+
+impl<I, B, F> StreamingIterator for MapWhile<I, B, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&I::Item) -> Option<B>,
+{
+    type Item = B;
+
+    #[inline]
+    fn advance(&mut self) {
+        if !self.done {
+            self.item = self.it.next().and_then(&mut self.f);
+            self.done = self.item.is_none();
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&B> {
+        self.item.as_ref()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let upper = if self.done {
+            Some(0)
+        } else {
+            self.it.size_hint().1
+        };
+        (0, upper)
+    }
+}
+
+impl<I, B, F> StreamingIteratorMut for MapWhile<I, B, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&I::Item) -> Option<B>,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut B> {
+        self.item.as_mut()
+    }
+}
+
+/// A regular, non-streaming iterator which transforms the elements of a streaming iterator.
+#[derive(Clone, Debug)]
+pub struct MapDeref<I, F> {
+    it: I,
+    f: F,
+}
+
+impl<I, B, F> Iterator for MapDeref<I, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.it.next().map(&mut self.f)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut map = self.f;
+        self.it.fold(init, move |acc, item| f(acc, map(item)))
+    }
+}
+
+impl<I, B, F> DoubleEndedIterator for MapDeref<I, F>
+where
+    I: DoubleEndedStreamingIterator,
+    F: FnMut(&I::Item) -> B,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.it.next_back().map(&mut self.f)
+    }
+
+    #[inline]
+    fn rfold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut map = self.f;
+        self.it.rfold(init, move |acc, item| f(acc, map(item)))
+    }
+}
+
+/// A regular, non-streaming iterator which transforms the elements of a mutable streaming iterator.
+#[derive(Clone, Debug)]
+pub struct MapDerefMut<I, F> {
+    it: I,
+    f: F,
+}
+
+impl<I, B, F> Iterator for MapDerefMut<I, F>
+where
+    I: StreamingIteratorMut,
+    F: FnMut(&mut I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.it.next_mut().map(&mut self.f)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut map = self.f;
+        self.it.fold_mut(init, move |acc, item| f(acc, map(item)))
+    }
+}
+
+impl<I, B, F> DoubleEndedIterator for MapDerefMut<I, F>
+where
+    I: DoubleEndedStreamingIteratorMut,
+    F: FnMut(&mut I::Item) -> B,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.it.next_back_mut().map(&mut self.f)
+    }
+
+    #[inline]
+    fn rfold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut map = self.f;
+        self.it.rfold_mut(init, move |acc, item| f(acc, map(item)))
+    }
+}
+
+/// A streaming iterator which transforms the elements of a streaming iterator.
+#[derive(Clone, Debug)]
+pub struct MapRef<I, F> {
+    it: I,
+    f: F,
+}
+
+impl<I, B: ?Sized, F> StreamingIterator for MapRef<I, F>
+where
+    I: StreamingIterator,
+    F: Fn(&I::Item) -> &B,
+{
+    type Item = B;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.it.advance();
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.it.is_done()
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&B> {
+        self.it.get().map(&self.f)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<&B> {
+        self.it.next().map(&self.f)
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let f = self.f;
+        self.it.fold(init, move |acc, item| fold(acc, f(item)))
+    }
+}
+
+/// A streaming iterator which projects the elements of a mutable streaming iterator to mutable
+/// references into them.
+#[derive(Clone, Debug)]
+pub struct MapRefMut<I, F, G> {
+    it: I,
+    f: F,
+    f_mut: G,
+}
+
+impl<I, B: ?Sized, F, G> StreamingIterator for MapRefMut<I, F, G>
+where
+    I: StreamingIterator,
+    F: Fn(&I::Item) -> &B,
+{
+    type Item = B;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.it.advance();
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.it.is_done()
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&B> {
+        self.it.get().map(&self.f)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<&B> {
+        self.it.next().map(&self.f)
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let f = self.f;
+        self.it.fold(init, move |acc, item| fold(acc, f(item)))
+    }
+}
+
+impl<I, B: ?Sized, F, G> StreamingIteratorMut for MapRefMut<I, F, G>
+where
+    I: StreamingIteratorMut,
+    F: Fn(&I::Item) -> &B,
+    G: FnMut(&mut I::Item) -> &mut B,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut B> {
+        self.it.get_mut().map(&mut self.f_mut)
+    }
+
+    #[inline]
+    fn next_mut(&mut self) -> Option<&mut B> {
+        self.it.next_mut().map(&mut self.f_mut)
+    }
+
+    #[inline]
+    fn fold_mut<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        let mut f_mut = self.f_mut;
+        self.it
+            .fold_mut(init, move |acc, item| fold(acc, f_mut(item)))
+    }
+}
+
+/// A normal, non-streaming, iterator which converts the elements of a streaming iterator into owned
+/// versions.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct Owned<I>(I);
+
+#[cfg(feature = "alloc")]
+impl<I> Iterator for Owned<I>
+where
+    I: StreamingIterator,
+    I::Item: ToOwned,
+{
+    type Item = <I::Item as ToOwned>::Owned;
+
+    #[inline]
+    fn next(&mut self) -> Option<<I::Item as ToOwned>::Owned> {
+        self.0.next().map(ToOwned::to_owned)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.0.fold(init, move |acc, item| f(acc, item.to_owned()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> DoubleEndedIterator for Owned<I>
+where
+    I: DoubleEndedStreamingIterator,
+    I::Item: Sized + ToOwned,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<<I::Item as ToOwned>::Owned> {
+        self.0.next_back().map(ToOwned::to_owned)
+    }
+
+    #[inline]
+    fn rfold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.0.rfold(init, move |acc, item| f(acc, item.to_owned()))
+    }
+}
+
+/// A streaming iterator which can look at the next element of a streaming iterator without
+/// consuming it.
+#[derive(Clone, Debug)]
+pub struct Peekable<I> {
+    it: I,
+    peeked: bool,
+}
+
+impl<I> Peekable<I>
+where
+    I: StreamingIterator,
+{
+    /// Returns the next element without consuming it.
+    #[inline]
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        if !self.peeked {
+            self.it.advance();
+            self.peeked = true;
+        }
+        self.it.get()
+    }
+
+    /// Consumes and returns the next element if it matches a predicate.
+    ///
+    /// If the predicate returns `false` (or there is no next element), `None` is returned and the
+    /// element stays peeked, so it will be returned by the next call to `next` or `peek`.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert, StreamingIterator};
+    /// let mut it = convert([1, 2, 3]).peekable();
+    /// assert_eq!(it.next_if(|&i| i == 1), Some(&1));
+    /// assert_eq!(it.next_if(|&i| i == 3), None);
+    /// assert_eq!(it.next(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn next_if<F>(&mut self, f: F) -> Option<&I::Item>
+    where
+        F: FnOnce(&I::Item) -> bool,
+    {
+        match self.peek() {
+            Some(item) if f(item) => {}
+            _ => return None,
+        }
+        self.peeked = false;
+        self.it.get()
+    }
+}
+
+impl<I> Peekable<I>
+where
+    I: StreamingIteratorMut,
+{
+    /// Returns the next element mutably without consuming it.
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<&mut I::Item> {
+        if !self.peeked {
+            self.it.advance();
+            self.peeked = true;
+        }
+        self.it.get_mut()
+    }
+}
+
+impl<I> StreamingIterator for Peekable<I>
+where
+    I: StreamingIterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        if self.peeked {
+            self.peeked = false;
+        } else {
+            self.it.advance();
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        // The peeked element isn't current yet, so `get` returns `None` until the next advance.
+        self.peeked || self.it.is_done()
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&I::Item> {
+        if self.peeked {
+            None
+        } else {
+            self.it.get()
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.it.size_hint();
+        if self.peeked && self.it.get().is_some() {
+            (
+                lower.saturating_add(1),
+                upper.and_then(|upper| upper.checked_add(1)),
+            )
+        } else {
+            (lower, upper)
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if self.peeked {
+            match self.it.get() {
+                Some(item) => acc = f(acc, item),
+                None => return acc,
+            }
+        }
+        self.it.fold(acc, f)
+    }
+}
+
+impl<I> StreamingIteratorMut for Peekable<I>
+where
+    I: StreamingIteratorMut,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut I::Item> {
+        if self.peeked {
+            None
+        } else {
+            self.it.get_mut()
+        }
+    }
+}
+
+/// A streaming iterator which reports the number of elements it has yielded to a closure.
+#[derive(Clone, Debug)]
+pub struct Progress<I, F> {
+    it: I,
+    f: F,
+    count: usize,
+}
+
+impl<I, F> StreamingIterator for Progress<I, F>
+where
+    I: StreamingIterator,
+    F: FnMut(usize, Option<usize>),
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.it.advance();
+        if !self.it.is_done() {
+            self.count += 1;
+            (self.f)(self.count, self.it.size_hint().1);
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.it.is_done()
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&I::Item> {
+        self.it.get()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+}
+
+impl<I, F> StreamingIteratorMut for Progress<I, F>
+where
+    I: StreamingIteratorMut,
+    F: FnMut(usize, Option<usize>),
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut I::Item> {
+        self.it.get_mut()
+    }
+}
+
+/// A streaming iterator which transforms the elements of a streaming iterator while threading a
+/// piece of state through the transformation, until the closure returns `None`.
+#[derive(Clone, Debug)]
+pub struct Scan<I, St, B, F> {
+    it: I,
+    f: F,
+    state: St,
+    item: Option<B>,
+    done: bool,
+}
+
+impl<I, St, B, F> Scan<I, St, B, F>
+where
+    I: StreamingIterator,
+{
+    #[inline]
+    fn advance_with<G>(&mut self, g: G)
+    where
+        G: FnOnce(&mut F, &mut St, &I::Item) -> Option<B>,
+    {
+        if !self.done {
+            let state = &mut self.state;
+            let f = &mut self.f;
+            self.item = self.it.next().and_then(|item| g(f, state, item));
+            self.done = self.item.is_none();
+        }
+    }
+}
+
+impl<I, St, B, F> StreamingIterator for Scan<I, St, B, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&mut St, &I::Item) -> Option<B>,
+{
+    type Item = B;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.advance_with(|f, state, item| f(state, item));
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&B> {
+        self.item.as_ref()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let upper = if self.done {
+            Some(0)
+        } else {
+            self.it.size_hint().1
+        };
+        (0, upper)
+    }
+}
+
+impl<I, St, B, F> StreamingIteratorMut for Scan<I, St, B, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&mut St, &I::Item) -> Option<B>,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut B> {
+        self.item.as_mut()
+    }
+}
+
+/// A streaming iterator which transforms the elements of a streaming iterator while threading a
+/// piece of state through the transformation.
+#[derive(Clone, Debug)]
+pub struct ScanState<I, St, B, F> {
+    scan: Scan<I, St, B, F>,
+}
+
+impl<I, St, B, F> ScanState<I, St, B, F> {
+    /// Consumes the iterator, returning the current state.
+    ///
+    /// This can be called at any point, but the state only reflects every element once the
+    /// underlying iterator has been exhausted.
+    #[inline]
+    pub fn into_state(self) -> St {
+        self.scan.state
+    }
+}
+
+impl<I, St, B, F> StreamingIterator for ScanState<I, St, B, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&mut St, &I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.scan
+            .advance_with(|f, state, item| Some(f(state, item)));
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&B> {
+        self.scan.item.as_ref()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.scan.it.size_hint()
+    }
+}
+
+impl<I, St, B, F> StreamingIteratorMut for ScanState<I, St, B, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&mut St, &I::Item) -> B,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut B> {
+        self.scan.item.as_mut()
+    }
+}
+
+/// A streaming iterator which skips a number of elements in a streaming iterator.
+#[derive(Clone, Debug)]
+pub struct Skip<I> {
+    it: I,
+    n: usize,
+}
+
+impl<I> StreamingIterator for Skip<I>
+where
+    I: StreamingIterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.it.nth(self.n);
+        self.n = 0;
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.it.is_done()
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&I::Item> {
+        self.it.get()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = self.it.size_hint();
+        (
+            hint.0.saturating_sub(self.n),
+            hint.1.map(|n| n.saturating_sub(self.n)),
+        )
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(mut self, init: Acc, fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        if self.n > 0 {
+            // nth(n) skips n+1
+            if self.it.nth(self.n - 1).is_none() {
+                return init;
+            }
+        }
+        self.it.fold(init, fold)
+    }
+}
+
+impl<I> Skip<I>
+where
+    I: StreamingIterator,
+{
+    // Skips the first `n` elements ahead of iterating from the back, so that the two ends meet
+    // at the right place. Returns `false` if that exhausted the iterator.
+    #[inline]
+    fn skip_front(&mut self) -> bool {
+        if self.n > 0 {
+            let n = core::mem::replace(&mut self.n, 0);
+            // nth(n) skips n+1
+            return self.it.nth(n - 1).is_some();
+        }
+        true
+    }
+}
+
+impl<I> DoubleEndedStreamingIterator for Skip<I>
+where
+    I: DoubleEndedStreamingIterator,
+{
+    #[inline]
+    fn advance_back(&mut self) {
+        if self.skip_front() {
+            self.it.advance_back();
+        }
+    }
+
+    #[inline]
+    fn rfold<Acc, Fold>(mut self, init: Acc, fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        if !self.skip_front() {
+            return init;
+        }
+        self.it.rfold(init, fold)
+    }
+}
+
+impl<I> ExactSizeStreamingIterator for Skip<I> where I: ExactSizeStreamingIterator {}
+
+impl<I> StreamingIteratorMut for Skip<I>
+where
+    I: StreamingIteratorMut,
+{
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.it.get_mut()
+    }
+
+    #[inline]
+    fn fold_mut<Acc, Fold>(mut self, init: Acc, fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        if self.n > 0 {
+            // nth(n) skips n+1
+            if self.it.nth(self.n - 1).is_none() {
+                return init;
+            }
+        }
+        self.it.fold_mut(init, fold)
+    }
+}
+
+impl<I> DoubleEndedStreamingIteratorMut for Skip<I>
+where
+    I: DoubleEndedStreamingIteratorMut,
+{
+    #[inline]
+    fn rfold_mut<Acc, Fold>(mut self, init: Acc, fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        if !self.skip_front() {
+            return init;
+        }
+        self.it.rfold_mut(init, fold)
+    }
+}
+
+/// A streaming iterator which skips initial elements that match a predicate
+#[derive(Clone, Debug)]
+pub struct SkipWhile<I, F> {
+    it: I,
+    f: F,
+    done: bool,
+}
+
+impl<I, F> StreamingIterator for SkipWhile<I, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        if !self.done {
+            let f = &mut self.f;
+            self.it.find(|i| !f(i));
+            self.done = true;
+        } else {
+            self.it.advance();
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.it.is_done()
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&I::Item> {
+        self.it.get()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = self.it.size_hint();
+        (0, hint.1)
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(mut self, mut init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        if !self.done {
+            match self.next() {
+                Some(item) => init = fold(init, item),
+                None => return init,
+            }
+        }
+        self.it.fold(init, fold)
+    }
+}
+
+impl<I, F> StreamingIteratorMut for SkipWhile<I, F>
+where
+    I: StreamingIteratorMut,
+    F: FnMut(&I::Item) -> bool,
+{
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.it.get_mut()
+    }
+
+    #[inline]
+    fn fold_mut<Acc, Fold>(mut self, mut init: Acc, mut fold: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        if !self.done {
+            match self.next_mut() {
+                Some(item) => init = fold(init, item),
+                None => return init,
+            }
+        }
+        self.it.fold_mut(init, fold)
+    }
+}
+
+/// A streaming iterator which steps over the elements of a streaming iterator by a fixed amount.
+#[derive(Clone, Debug)]
+pub struct StepBy<I> {
+    it: I,
+    step: usize,
+    first: bool,
+}
+
+impl<I> StreamingIterator for StepBy<I>
+where
+    I: StreamingIterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        if self.first {
+            self.first = false;
+            self.it.advance();
+        } else {
+            self.it.nth(self.step);
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.it.is_done()
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&I::Item> {
+        self.it.get()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let step = self.step + 1;
+        let first = self.first;
+        let len = |n: usize| {
+            if !first {
+                n / step
+            } else if n == 0 {
+                0
+            } else {
+                1 + (n - 1) / step
+            }
+        };
+        let (lower, upper) = self.it.size_hint();
+        (len(lower), upper.map(len))
+    }
+}
+
+impl<I> StreamingIteratorMut for StepBy<I>
+where
+    I: StreamingIteratorMut,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut I::Item> {
+        self.it.get_mut()
+    }
+}
+
+/// A streaming iterator which only yields a limited number of elements in a streaming iterator.
+#[derive(Clone, Debug)]
+pub struct Take<I> {
+    it: I,
+    n: usize,
+    done: bool,
+}
+
+impl<I> StreamingIterator for Take<I>
+where
+    I: StreamingIterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        if self.n != 0 {
+            self.it.advance();
+            self.n -= 1;
+        } else {
+            self.done = true;
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.done || self.it.is_done()
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&I::Item> {
+        if self.done {
+            None
+        } else {
+            self.it.get()
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.it.size_hint();
+        let upper = match upper {
+            Some(upper) => cmp::min(upper, self.n),
+            None => self.n,
+        };
+        (cmp::min(lower, self.n), Some(upper))
+    }
+}
+
+impl<I> DoubleEndedStreamingIterator for Take<I>
+where
+    I: DoubleEndedStreamingIterator + ExactSizeStreamingIterator,
+{
+    #[inline]
+    fn advance_back(&mut self) {
+        if self.n != 0 {
+            for _ in 0..self.it.len().saturating_sub(self.n) {
+                self.it.advance_back();
+            }
+            self.it.advance_back();
+            self.n -= 1;
+        } else {
+            self.done = true;
+        }
+    }
+}
+
+impl<I> ExactSizeStreamingIterator for Take<I> where I: ExactSizeStreamingIterator {}
+
+impl<I> StreamingIteratorMut for Take<I>
+where
+    I: StreamingIteratorMut,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut I::Item> {
+        if self.done {
+            None
+        } else {
+            self.it.get_mut()
+        }
+    }
+}
+
+impl<I> DoubleEndedStreamingIteratorMut for Take<I> where
+    I: DoubleEndedStreamingIteratorMut + ExactSizeStreamingIterator
+{
+}
+
+/// A streaming iterator which only returns initial elements matching a predicate.
+#[derive(Clone, Debug)]
+pub struct TakeWhile<I, F> {
+    it: I,
+    f: F,
+    done: bool,
+}
+
+impl<I, F> StreamingIterator for TakeWhile<I, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        if !self.done {
+            self.it.advance();
+            match self.it.get() {
+                Some(i) => {
+                    if !(self.f)(i) {
+                        self.done = true;
+                    }
+                }
+                // Running out also counts as done, so `size_hint` drops to `Some(0)` even if the
+                // underlying iterator has no upper bound.
+                None => self.done = true,
+            }
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.done || self.it.is_done()
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&I::Item> {
+        if self.done {
+            None
+        } else {
+            self.it.get()
+        }
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<&I::Item> {
+        if self.done {
+            None
+        } else {
+            match self.it.next() {
+                Some(i) if (self.f)(i) => Some(i),
+                _ => {
+                    self.done = true;
+                    None
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let upper = if self.done {
+            Some(0)
+        } else {
+            self.it.size_hint().1
+        };
+        (0, upper)
+    }
+}
+
+impl<I, F> StreamingIteratorMut for TakeWhile<I, F>
+where
+    I: StreamingIteratorMut,
+    F: FnMut(&I::Item) -> bool,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut I::Item> {
+        if self.done {
+            None
+        } else {
+            self.it.get_mut()
+        }
+    }
+}
+
+/// A streaming iterator which returns initial elements matching a predicate, followed by the first
+/// element which does not match it.
+#[derive(Clone, Debug)]
+pub struct TakeWhileInclusive<I, F> {
+    it: I,
+    f: F,
+    done: bool,
+    exhausted: bool,
+}
+
+impl<I, F> StreamingIterator for TakeWhileInclusive<I, F>
+where
+    I: StreamingIterator,
+    F: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        if self.done {
+            self.exhausted = true;
+        } else {
+            self.it.advance();
+            match self.it.get() {
+                Some(i) => self.done = !(self.f)(i),
+                None => {
+                    self.done = true;
+                    self.exhausted = true;
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.exhausted || self.it.is_done()
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&I::Item> {
+        if self.exhausted {
+            None
+        } else {
+            self.it.get()
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (lower, upper) = self.it.size_hint();
+            (cmp::min(lower, 1), upper)
+        }
+    }
+}
+
+impl<I, F> StreamingIteratorMut for TakeWhileInclusive<I, F>
+where
+    I: StreamingIteratorMut,
+    F: FnMut(&I::Item) -> bool,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut I::Item> {
+        if self.exhausted {
+            None
+        } else {
+            self.it.get_mut()
+        }
+    }
+}
+
+/// A streaming iterator which buffers a row-major matrix and yields its elements in column-major
+/// order.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct Transpose<I>
+where
+    I: StreamingIterator,
+    I::Item: Sized,
+{
+    it: Option<I>,
+    buf: Vec<I::Item>,
+    cols: usize,
+    front: usize,
+    back: usize,
+    current: Option<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Transpose<I>
+where
+    I: StreamingIterator,
+    I::Item: Clone,
+{
+    fn fill(&mut self) {
+        if let Some(it) = self.it.take() {
+            let mut buf = it.fold(Vec::new(), |mut buf, item| {
+                buf.push(item.clone());
+                buf
+            });
+            buf.truncate(buf.len() - buf.len() % self.cols);
+            self.back = buf.len();
+            self.buf = buf;
+        }
+    }
+
+    fn index(&self, k: usize) -> usize {
+        slice::column_major_index(k, self.buf.len() / self.cols, self.cols)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> StreamingIterator for Transpose<I>
+where
+    I: StreamingIterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.fill();
+        if self.front < self.back {
+            self.current = Some(self.front);
+            self.front += 1;
+        } else {
+            self.current = None;
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.current.is_none()
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&I::Item> {
+        self.current.map(|k| &self.buf[self.index(k)])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.it {
+            Some(ref it) => {
+                let (lower, upper) = it.size_hint();
+                (lower - lower % self.cols, upper.map(|n| n - n % self.cols))
+            }
+            None => (self.back - self.front, Some(self.back - self.front)),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> DoubleEndedStreamingIterator for Transpose<I>
+where
+    I: StreamingIterator,
+    I::Item: Clone,
+{
+    #[inline]
+    fn advance_back(&mut self) {
+        self.fill();
+        if self.front < self.back {
+            self.back -= 1;
+            self.current = Some(self.back);
+        } else {
+            self.current = None;
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> StreamingIteratorMut for Transpose<I>
+where
+    I: StreamingIterator,
+    I::Item: Clone,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut I::Item> {
+        let i = self.index(self.current?);
+        Some(&mut self.buf[i])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> DoubleEndedStreamingIteratorMut for Transpose<I>
+where
+    I: StreamingIterator,
+    I::Item: Clone,
+{
+}
+
+/// A streaming iterator which buffers the owned elements of a streaming iterator and returns
+/// them in the opposite order.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub struct RevBuffered<I>
+where
+    I: StreamingIterator,
+    I::Item: ToOwned,
+{
+    it: I,
+    buf: Vec<<I::Item as ToOwned>::Owned>,
+    filled: bool,
+    // The remaining elements are `buf[front..back]`.
+    front: usize,
+    back: usize,
+    current: Option<usize>,
+}
+
+// Implemented by hand for the same reason as the impls for `Chunks`.
+#[cfg(feature = "alloc")]
+impl<I> Clone for RevBuffered<I>
+where
+    I: StreamingIterator + Clone,
+    I::Item: ToOwned,
+    <I::Item as ToOwned>::Owned: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        RevBuffered {
+            it: self.it.clone(),
+            buf: self.buf.clone(),
+            filled: self.filled,
+            front: self.front,
+            back: self.back,
+            current: self.current,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> core::fmt::Debug for RevBuffered<I>
+where
+    I: StreamingIterator + core::fmt::Debug,
+    I::Item: ToOwned,
+    <I::Item as ToOwned>::Owned: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RevBuffered")
+            .field("it", &self.it)
+            .field("buf", &self.buf)
+            .field("filled", &self.filled)
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .field("current", &self.current)
+            .finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> RevBuffered<I>
+where
+    I: StreamingIterator,
+    I::Item: ToOwned,
+{
+    #[inline]
+    fn fill(&mut self) {
+        if !self.filled {
+            self.filled = true;
+            while let Some(item) = self.it.next() {
+                self.buf.push(item.to_owned());
+            }
+            self.back = self.buf.len();
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> StreamingIterator for RevBuffered<I>
+where
+    I: StreamingIterator,
+    I::Item: ToOwned,
+{
+    type Item = <I::Item as ToOwned>::Owned;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.fill();
+        if self.front < self.back {
+            self.back -= 1;
+            self.current = Some(self.back);
+        } else {
+            self.current = None;
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.map(|i| &self.buf[i])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.filled {
+            let len = self.back - self.front;
+            (len, Some(len))
+        } else {
+            self.it.size_hint()
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> DoubleEndedStreamingIterator for RevBuffered<I>
+where
+    I: StreamingIterator,
+    I::Item: ToOwned,
+{
+    #[inline]
+    fn advance_back(&mut self) {
+        self.fill();
+        if self.front < self.back {
+            self.current = Some(self.front);
+            self.front += 1;
+        } else {
+            self.current = None;
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> ExactSizeStreamingIterator for RevBuffered<I>
+where
+    I: ExactSizeStreamingIterator,
+    I::Item: ToOwned,
+{
+}
+
+#[cfg(feature = "alloc")]
+impl<I> StreamingIteratorMut for RevBuffered<I>
+where
+    I: StreamingIterator,
+    I::Item: ToOwned,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.current.map(move |i| &mut self.buf[i])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> DoubleEndedStreamingIteratorMut for RevBuffered<I>
+where
+    I: StreamingIterator,
+    I::Item: ToOwned,
+{
+}
+
+/// A streaming iterator which returns elements in the opposite order.
+pub struct Rev<I>(I);
+
+impl<I> StreamingIterator for Rev<I>
+where
+    I: DoubleEndedStreamingIterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.0.advance_back();
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.0.is_done()
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&I::Item> {
+        self.0.get()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<&I::Item> {
+        self.0.next_back()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        self.0.rfold(init, f)
+    }
+}
+
+impl<I> DoubleEndedStreamingIterator for Rev<I>
+where
+    I: DoubleEndedStreamingIterator,
+{
+    #[inline]
+    fn advance_back(&mut self) {
+        self.0.advance();
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<&I::Item> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn rfold<Acc, Fold>(self, init: Acc, f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        self.0.fold(init, f)
+    }
+}
+
+impl<I> StreamingIteratorMut for Rev<I>
+where
+    I: DoubleEndedStreamingIteratorMut,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut I::Item> {
+        self.0.get_mut()
+    }
+
+    #[inline]
+    fn fold_mut<B, F>(self, init: B, f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, &mut Self::Item) -> B,
+    {
+        self.0.rfold_mut(init, f)
+    }
+}
+
+impl<I> DoubleEndedStreamingIteratorMut for Rev<I>
+where
+    I: DoubleEndedStreamingIteratorMut,
+{
+    #[inline]
+    fn rfold_mut<B, F>(self, init: B, f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, &mut Self::Item) -> B,
+    {
+        self.0.fold_mut(init, f)
+    }
+}
+
+/// A streaming iterator which only yields elements that differ significantly from the last one it
+/// yielded.
+#[derive(Clone, Debug)]
+pub struct ChangesBy<I, F, D>
+where
+    I: StreamingIterator,
+    I::Item: Sized,
+{
+    it: I,
+    metric: F,
+    threshold: D,
+    last: Option<I::Item>,
+}
+
+impl<I, F, D> StreamingIterator for ChangesBy<I, F, D>
+where
+    I: StreamingIterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> D,
+    D: PartialOrd,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        while let Some(item) = self.it.next() {
+            match self.last {
+                Some(ref mut last) => {
+                    if (self.metric)(last, item) > self.threshold {
+                        last.clone_from(item);
+                        break;
+                    }
+                }
+                None => {
+                    self.last = Some(item.clone());
+                    break;
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.it.is_done()
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&I::Item> {
+        self.it.get()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.it.size_hint();
+        let lower = if self.last.is_none() {
+            cmp::min(lower, 1)
+        } else {
+            0
+        };
+        (lower, upper)
+    }
+}
+
+/// A streaming iterator which combines each element of a streaming iterator with the preceding
+/// one.
+#[derive(Clone, Debug)]
+pub struct Pairwise<I, B, F>
+where
+    I: StreamingIterator,
+    I::Item: Sized,
+{
+    it: I,
+    f: F,
+    prev: Option<I::Item>,
+    item: Option<B>,
+}
+
+impl<I, B, F> StreamingIterator for Pairwise<I, B, F>
+where
+    I: StreamingIterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn advance(&mut self) {
+        if self.prev.is_none() {
+            self.prev = self.it.next().cloned();
+        }
+        self.item = match (&mut self.prev, self.it.next()) {
+            (Some(prev), Some(cur)) => {
+                let item = (self.f)(prev, cur);
+                prev.clone_from(cur);
+                Some(item)
+            }
+            _ => None,
+        };
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&B> {
+        self.item.as_ref()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.it.size_hint();
+        if self.prev.is_some() {
+            (lower, upper)
+        } else {
+            (
+                lower.saturating_sub(1),
+                upper.map(|upper| upper.saturating_sub(1)),
+            )
+        }
+    }
+}
+
+impl<I, B, F> StreamingIteratorMut for Pairwise<I, B, F>
+where
+    I: StreamingIterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> B,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut B> {
+        self.item.as_mut()
+    }
+}
+
+/// A streaming iterator which flags the last element of a streaming iterator.
+#[derive(Clone, Debug)]
+pub struct WithLast<I>
+where
+    I: StreamingIterator,
+    I::Item: Sized,
+{
+    it: I,
+    item: Option<(I::Item, bool)>,
+    first: bool,
+}
+
+impl<I> StreamingIterator for WithLast<I>
+where
+    I: StreamingIterator,
+    I::Item: Clone,
+{
+    type Item = (I::Item, bool);
+
+    #[inline]
+    fn advance(&mut self) {
+        if self.first {
+            self.first = false;
+            self.it.advance();
+        }
+        self.item = match self.it.get() {
+            Some(item) => {
+                let item = item.clone();
+                self.it.advance();
+                Some((item, self.it.is_done()))
+            }
+            None => None,
+        };
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&Self::Item> {
+        self.item.as_ref()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.it.size_hint();
+        if self.first || self.it.is_done() {
+            (lower, upper)
+        } else {
+            (
+                lower.saturating_add(1),
+                upper.and_then(|upper| upper.checked_add(1)),
+            )
+        }
+    }
+}
+
+impl<I> StreamingIteratorMut for WithLast<I>
+where
+    I: StreamingIterator,
+    I::Item: Clone,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.item.as_mut()
+    }
+}
+
+/// A streaming iterator which pairs the elements of a streaming iterator with successive slots of
+/// a mutable slice.
+///
+/// Unlike most adapters, this does not implement `Clone`, since it holds a unique borrow of the
+/// output slice.
+#[derive(Debug)]
+pub struct ZipWrite<'b, I, T> {
+    it: I,
+    out: &'b mut [T],
+    pos: usize,
+    done: bool,
+}
+
+impl<'b, I, T> ZipWrite<'b, I, T>
+where
+    I: StreamingIterator,
+{
+    /// Returns a mutable reference to the output slot paired with the current element.
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        if self.pos == 0 || self.is_done() {
+            None
+        } else {
+            self.out.get_mut(self.pos - 1)
+        }
+    }
+
+    /// Returns the current element together with its output slot.
+    #[inline]
+    pub fn get_pair(&mut self) -> Option<(&I::Item, &mut T)> {
+        if self.pos == 0 || self.done {
+            return None;
+        }
+        let item = self.it.get()?;
+        let slot = self.out.get_mut(self.pos - 1)?;
+        Some((item, slot))
+    }
+
+    /// Advances the iterator and returns the next element together with its output slot.
+    #[inline]
+    pub fn next_pair(&mut self) -> Option<(&I::Item, &mut T)> {
+        self.advance();
+        self.get_pair()
+    }
+}
+
+impl<'b, I, T> StreamingIterator for ZipWrite<'b, I, T>
+where
+    I: StreamingIterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        if self.pos < self.out.len() {
+            self.it.advance();
+            self.pos += 1;
+        } else {
+            self.done = true;
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.done || self.it.is_done()
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&I::Item> {
+        if self.done {
+            None
+        } else {
+            self.it.get()
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.out.len() - self.pos;
+        let (lower, upper) = self.it.size_hint();
+        let upper = match upper {
+            Some(upper) => cmp::min(upper, remaining),
+            None => remaining,
+        };
+        (cmp::min(lower, remaining), Some(upper))
+    }
+}
+
+/// A streaming iterator which pairs the elements of two mutable streaming iterators.
+#[derive(Clone, Debug)]
+pub struct ZipMut<A, B> {
+    a: A,
+    b: B,
+    done: bool,
+}
+
+impl<A, B> ZipMut<A, B>
+where
+    A: StreamingIteratorMut,
+    B: StreamingIteratorMut,
+{
+    /// Returns mutable references to the current elements of both iterators.
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<(&mut A::Item, &mut B::Item)> {
+        if self.done {
+            return None;
+        }
+        let a = self.a.get_mut()?;
+        let b = self.b.get_mut()?;
+        Some((a, b))
+    }
+
+    /// Advances both iterators and returns mutable references to their next elements.
+    #[inline]
+    pub fn next_mut(&mut self) -> Option<(&mut A::Item, &mut B::Item)> {
+        self.advance();
+        self.get_mut()
+    }
+}
+
+impl<A, B> StreamingIterator for ZipMut<A, B>
+where
+    A: StreamingIteratorMut,
+    B: StreamingIteratorMut,
+{
+    type Item = A::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        if self.done {
+            return;
+        }
+        self.a.advance();
+        if self.a.is_done() {
+            self.done = true;
+            return;
+        }
+        self.b.advance();
+        if self.b.is_done() {
+            self.done = true;
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&A::Item> {
+        if self.done {
+            None
+        } else {
+            self.a.get()
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(cmp::min(a, b)),
+            (a, None) => a,
+            (None, b) => b,
+        };
+        (cmp::min(a_lower, b_lower), upper)
+    }
+}
+
+/// A streaming iterator which combines the elements of two streaming iterators with a closure.
+#[derive(Clone, Debug)]
+pub struct ZipMap<A, B, T, F> {
+    a: A,
+    b: B,
+    f: F,
+    item: Option<T>,
+}
+
+impl<A, B, T, F> StreamingIterator for ZipMap<A, B, T, F>
+where
+    A: StreamingIterator,
+    B: StreamingIterator,
+    F: FnMut(&A::Item, &B::Item) -> T,
+{
+    type Item = T;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.item = match (self.a.next(), self.b.next()) {
+            (Some(a), Some(b)) => Some((self.f)(a, b)),
+            _ => None,
+        };
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&T> {
+        self.item.as_ref()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(cmp::min(a, b)),
+            (a, None) => a,
+            (None, b) => b,
+        };
+        (cmp::min(a_lower, b_lower), upper)
+    }
+}
+
+impl<A, B, T, F> StreamingIteratorMut for ZipMap<A, B, T, F>
+where
+    A: StreamingIterator,
+    B: StreamingIterator,
+    F: FnMut(&A::Item, &B::Item) -> T,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut T> {
+        self.item.as_mut()
+    }
+}
+
+/// A streaming iterator which combines the elements of two streaming iterators with a closure
+/// until both are exhausted.
+#[derive(Clone, Debug)]
+pub struct ZipLongestMap<A, B, T, F> {
+    a: A,
+    b: B,
+    a_done: bool,
+    b_done: bool,
+    f: F,
+    item: Option<T>,
+}
+
+impl<A, B, T, F> StreamingIterator for ZipLongestMap<A, B, T, F>
+where
+    A: StreamingIterator,
+    B: StreamingIterator,
+    F: FnMut(Option<&A::Item>, Option<&B::Item>) -> T,
+{
+    type Item = T;
+
+    #[inline]
+    fn advance(&mut self) {
+        let a = if self.a_done { None } else { self.a.next() };
+        let b = if self.b_done { None } else { self.b.next() };
+        self.a_done = a.is_none();
+        self.b_done = b.is_none();
+        self.item = match (a, b) {
+            (None, None) => None,
+            (a, b) => Some((self.f)(a, b)),
+        };
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&T> {
+        self.item.as_ref()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = if self.a_done {
+            (0, Some(0))
+        } else {
+            self.a.size_hint()
+        };
+        let (b_lower, b_upper) = if self.b_done {
+            (0, Some(0))
+        } else {
+            self.b.size_hint()
+        };
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(cmp::max(a, b)),
+            _ => None,
+        };
+        (cmp::max(a_lower, b_lower), upper)
+    }
+}
+
+impl<A, B, T, F> StreamingIteratorMut for ZipLongestMap<A, B, T, F>
+where
+    A: StreamingIterator,
+    B: StreamingIterator,
+    F: FnMut(Option<&A::Item>, Option<&B::Item>) -> T,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut T> {
+        self.item.as_mut()
+    }
+}
+
+/// Conversion of a streaming iterator into a normal iterator over references to its elements.
+///
+/// A general streaming iterator cannot be turned into an `Iterator<Item = &Self::Item>`: its
+/// elements may live inside the iterator itself and be overwritten by the next call to
+/// `advance`, so a reference to one element cannot be held while the following one is produced.
+/// This trait is only implemented by sources whose elements live in storage outside the iterator,
+/// such as a slice, so that references to them remain valid for `'a` regardless of how the
+/// iterator is advanced.
+pub trait AsRefIterator<'a>: StreamingIterator
+where
+    Self::Item: 'a,
+{
+    /// The type of the normal iterator.
+    type RefIter: Iterator<Item = &'a Self::Item>;
+
+    /// Converts this streaming iterator into a normal iterator over its remaining elements.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert_ref, AsRefIterator, StreamingIterator};
+    /// let items = [1, 2, 3, 4];
+    /// let mut it = convert_ref(&items);
+    /// it.advance();
+    /// let evens: Vec<&i32> = it.ref_iter().filter(|&&i| i % 2 == 0).collect();
+    /// assert_eq!(evens, [&2, &4]);
+    /// ```
+    fn ref_iter(self) -> Self::RefIter;
+}
+
+/// Conversion from [`IntoIterator`] to [`StreamingIterator`].
+pub trait IntoStreamingIterator: IntoIterator
+where
+    Self: Sized,
+{
+    /// Turns an [`IntoIterator`] into a [`StreamingIterator`].
+    ///
+    /// Calling this method on an [`IntoIterator`] is equivalent to using [`convert`].
+    #[inline]
+    fn into_streaming_iter(self) -> Convert<Self::IntoIter> {
+        convert(self)
+    }
+
+    /// Turns an [`IntoIterator`] of references into a [`StreamingIterator`].
+    ///
+    /// Calling this method on an [`IntoIterator`] is equivalent to using [`convert_ref`].
+    #[inline]
+    fn into_streaming_iter_ref<'a, T: ?Sized>(self) -> ConvertRef<'a, Self::IntoIter, T>
+    where
+        Self: IntoIterator<Item = &'a T>,
+    {
+        convert_ref(self)
+    }
+
+    /// Turns an [`IntoIterator`] of mutable references into a [`StreamingIteratorMut`].
+    ///
+    /// Calling this method on an [`IntoIterator`] is equivalent to using [`convert_mut`].
+    #[inline]
+    fn into_streaming_iter_mut<'a, T: ?Sized>(self) -> ConvertMut<'a, Self::IntoIter, T>
+    where
+        Self: IntoIterator<Item = &'a mut T>,
+    {
+        convert_mut(self)
+    }
+}
+
+impl<I> IntoStreamingIterator for I where I: IntoIterator {}
+
+/// Extends a collection with owned copies of the elements of a streaming iterator.
+///
+/// Requires the `alloc` feature.
+///
+/// ```
+/// # use streaming_iterator::convert_ref;
+/// let mut words = vec![String::from("hello")];
+/// streaming_iterator::extend(&mut words, convert_ref(["big", "world"]));
+/// assert_eq!(words, ["hello", "big", "world"]);
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn extend<C, I>(collection: &mut C, it: I)
+where
+    I: StreamingIterator,
+    I::Item: ToOwned,
+    C: Extend<<I::Item as ToOwned>::Owned>,
+{
+    it.for_each(|item| collection.extend(Some(item.to_owned())));
+}
+
+#[cfg(test)]
+mod test {
+    use core::fmt::Debug;
+
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn test<I>(mut it: I, expected: &[I::Item])
+    where
+        I: StreamingIterator,
+        I::Item: Sized + PartialEq + Debug,
+    {
+        for item in expected {
+            it.advance();
+            assert_eq!(it.get(), Some(item));
+            assert_eq!(it.get(), Some(item));
+        }
+        it.advance();
+        assert_eq!(it.get(), None);
+        assert_eq!(it.get(), None);
+    }
+
+    fn test_back<I>(mut it: I, expected: &[I::Item])
+    where
+        I: DoubleEndedStreamingIterator,
+        I::Item: Sized + PartialEq + Debug,
+    {
+        for item in expected {
+            it.advance_back();
+            assert_eq!(it.get(), Some(item));
+            assert_eq!(it.get(), Some(item));
+        }
+        it.advance_back();
+        assert_eq!(it.get(), None);
+        assert_eq!(it.get(), None);
+    }
+
+    fn test_deref<I>(mut it: I, expected: &[I::Item])
+    where
+        I: Iterator,
+        I::Item: Sized + PartialEq + Debug,
+    {
+        for item in expected {
+            assert_eq!(it.next().as_ref(), Some(item));
+        }
+        assert_eq!(it.next(), None)
+    }
+
+    #[test]
+    fn all() {
+        let items = [0, 1, 2];
+        let mut it = convert(items);
+        assert!(it.clone().all(|&i| i < 3));
+        assert!(!it.all(|&i| i % 2 == 0));
+    }
+
+    #[test]
+    fn any() {
+        let items = [0, 1, 2];
+        let mut it = convert(items);
+        assert!(it.clone().any(|&i| i > 1));
+        assert!(!it.any(|&i| i > 2));
+    }
+
+    #[test]
+    fn test_chain() {
+        let items_a = [0, 1, 2, 3];
+        let items_b = [10, 20, 30];
+        let expected = [0, 1, 2, 3, 10, 20, 30];
+
+        let it = convert(items_a).chain(convert(items_b));
+        test(it, &expected);
+    }
+
+    #[test]
+    fn test_chain_back() {
+        let items_a = [0, 1, 2, 3];
+        let items_b = [10, 20, 30];
+        let expected = [30, 20, 10, 3, 2, 1, 0];
+
+        let it = convert(items_a).chain(convert(items_b));
+        test_back(it, &expected);
+    }
+
+    #[test]
+    fn test_chain_mixed() {
+        let items_a = [0, 1, 2, 3];
+        let items_b = [10, 20, 30];
+
+        let mut it = convert(items_a).chain(convert(items_b));
+
+        assert_eq!(it.get(), None);
+        it.advance();
+        assert_eq!(it.get().copied(), Some(0));
+        it.advance_back();
+        assert_eq!(it.get().copied(), Some(30));
+        it.advance();
+        assert_eq!(it.get().copied(), Some(1));
+        it.advance_back();
+        assert_eq!(it.get().copied(), Some(20));
+        it.advance();
+        assert_eq!(it.get().copied(), Some(2));
+        it.advance_back();
+        assert_eq!(it.get().copied(), Some(10));
+        it.advance_back();
+        assert_eq!(it.get().copied(), Some(3));
+    }
+
+    #[test]
+    fn test_chain_size_hint() {
+        fn check<I: StreamingIterator + Clone>(it: &I) {
+            let remaining = it.clone().count();
+            assert_eq!(it.size_hint(), (remaining, Some(remaining)));
+        }
+
+        let items_a = [0, 1, 2, 3];
+        let items_b = [10, 20, 30];
+
+        let mut it = convert(items_a).chain(convert(items_b));
+        check(&it);
+        it.advance();
+        check(&it);
+        it.advance_back();
+        check(&it);
+        it.advance();
+        check(&it);
+        it.advance_back();
+        it.advance_back();
+        check(&it);
+        it.advance_back();
+        assert_eq!(it.get().copied(), Some(3));
+        check(&it);
+        it.advance();
+        check(&it);
+
+        let mut it = convert(items_a).chain(convert(items_b));
+        for _ in 0..5 {
+            it.advance();
+        }
+        assert_eq!(it.get().copied(), Some(10));
+        check(&it);
+
+        let it = convert(0..usize::MAX).chain(convert(0..2));
+        assert_eq!(it.size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn cloned() {
+        let items = [0, 1];
+        let mut it = convert(items).cloned();
+        assert_eq!(it.next(), Some(0));
+        assert_eq!(it.next(), Some(1));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn copied() {
+        let items = [0, 1];
+        let mut it = convert(items).copied();
+        assert_eq!(it.next(), Some(0));
+        assert_eq!(it.next(), Some(1));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_convert() {
+        let items = [0, 1];
+        let it = convert(items);
+        test(it, &items);
+    }
+
+    #[test]
+    fn test_convert_ref() {
+        let items = [&0, &1];
+        let it = convert_ref(items.iter());
+        test(it, &items);
+    }
+
+    #[test]
+    fn count() {
+        let items = [0, 1, 2, 3];
+        let it = convert(items);
+        assert_eq!(it.count(), 4);
+    }
+
+    #[test]
+    fn filter() {
+        let items = [0, 1, 2, 3];
+        let it = convert(items).filter(|x| x % 2 == 0);
+        test(it, &[0, 2]);
+    }
+
+    #[test]
+    fn filter_is_done() {
+        let mut it = convert([1, 3, 5]).filter(|x| x % 2 == 0);
+        assert!(it.is_done());
+        assert_eq!(it.get(), None);
+        it.advance();
+        assert!(it.is_done());
+        assert_eq!(it.get(), None);
+        it.advance();
+        assert!(it.is_done());
+
+        let mut it = convert([1, 2, 3]).filter(|&x| x == 2);
+        assert!(it.is_done());
+        it.advance();
+        assert!(!it.is_done());
+        assert_eq!(it.get(), Some(&2));
+        it.advance_back();
+        assert!(it.is_done());
+        assert_eq!(it.get_mut(), None);
+    }
+
+    #[test]
+    fn fuse() {
+        struct Flicker(i32);
+
+        impl StreamingIterator for Flicker {
+            type Item = i32;
+
+            fn advance(&mut self) {
+                self.0 += 1;
+            }
+
+            fn get(&self) -> Option<&i32> {
+                if self.0 % 4 == 3 {
+                    None
+                } else {
+                    Some(&self.0)
+                }
+            }
+        }
+
+        let mut it = Flicker(0).fuse();
+        assert_eq!(it.get(), None);
+        it.advance();
+        assert_eq!(it.get(), Some(&1));
+        assert_eq!(it.get(), Some(&1));
+        it.advance();
+        assert_eq!(it.get(), Some(&2));
+        assert_eq!(it.get(), Some(&2));
+        it.advance();
+        assert_eq!(it.get(), None);
+        assert_eq!(it.get(), None);
+        it.advance();
+        assert_eq!(it.get(), None);
+        assert_eq!(it.get(), None);
+    }
+
+    #[test]
+    fn inspect() {
+        let items = [0, 1, 2, 3];
+        let mut idx = 0;
+        let mut items_inspected = [-1, -1, -1, -1];
+
+        {
+            let it = convert(items).inspect(|&i| {
+                items_inspected[idx] = i;
+                idx += 1;
+            });
+
+            test(it, &items);
+        }
+
+        assert_eq!(&items_inspected, &items);
+    }
+
+    #[test]
+    fn map() {
+        let items = [0, 1];
+        let it = convert(items.iter().map(|&i| i as usize)).map(|&i| i as i32);
+        test(it, &items);
+    }
+
+    #[test]
+    fn map_deref() {
+        let items = [0, 1];
+        let it = convert(items.iter().map(|&i| i as usize)).map_deref(|&i| i as i32);
+        test_deref(it, &items);
+    }
+
+    #[test]
+    fn map_deref_mut() {
+        let mut items = [1, 2, 3];
+        {
+            let it = convert_mut(&mut items).map_deref_mut(|i| -core::mem::replace(i, 0));
+            test_deref(it, &[-1, -2, -3]);
+        }
+        assert_eq!(items, [0, 0, 0]);
+    }
+
+    #[test]
+    fn map_ref() {
+        #[derive(Clone)]
+        struct Foo(i32);
+
+        let items = [Foo(0), Foo(1)];
+        let it = convert(items).map_ref(|f| &f.0);
+        test(it, &[0, 1]);
+    }
+
+    #[test]
+    fn flat_map() {
+        let items = [[0, 1, 2], [3, 4, 5]];
+        let it = convert(items).flat_map(|&i| convert(i));
+
+        test(it, &[0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn flatten() {
+        let mut items = [
+            convert_ref([].as_ref()),
+            convert_ref([1].as_ref()),
+            convert_ref([].as_ref()),
+            convert_ref([2, 3].as_ref()),
+            convert_ref([].as_ref()),
+        ];
+        let it = convert_mut(&mut items).flatten();
+
+        test(it, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn flatten_unsized() {
+        type DynI32 = dyn StreamingIterator<Item = i32>;
+        let mut items = [
+            &mut once(1) as &mut DynI32,
+            &mut empty(),
+            &mut convert(2..=3),
+        ];
+        let iters = items.iter_mut().map(|iter| &mut **iter);
+        let it = convert_mut(iters).flatten();
+
+        test(it, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn nth() {
+        let items = [0, 1];
+        let mut it = convert(items);
+        assert_eq!(it.clone().nth(0), Some(&0));
+        assert_eq!(it.clone().nth(1), Some(&1));
+        assert_eq!(it.nth(2), None);
+    }
+
+    #[test]
+    fn nth_convert_skips_inner() {
+        use core::cell::Cell;
+
+        struct Counted<'a> {
+            range: core::ops::Range<usize>,
+            produced: &'a Cell<usize>,
+        }
+
+        impl Iterator for Counted<'_> {
+            type Item = usize;
+
+            fn next(&mut self) -> Option<usize> {
+                self.produced.set(self.produced.get() + 1);
+                self.range.next()
+            }
+
+            fn nth(&mut self, n: usize) -> Option<usize> {
+                self.produced.set(self.produced.get() + 1);
+                self.range.nth(n)
+            }
+        }
+
+        let produced = Cell::new(0);
+        let source = Counted {
+            range: 0..1_000_000,
+            produced: &produced,
+        };
+        let mut it = convert(source).skip(999_999);
+        assert_eq!(it.next(), Some(&999_999));
+        assert_eq!(produced.get(), 1);
+        assert_eq!(it.next(), None);
+        assert_eq!(produced.get(), 2);
+
+        let produced = Cell::new(0);
+        let mut it = convert(Counted {
+            range: 0..10,
+            produced: &produced,
+        });
+        assert_eq!(it.nth(7), Some(&7));
+        assert_eq!(produced.get(), 1);
+    }
+
+    #[test]
+    fn filter_map() {
+        let items = [0u8, 1, 1, 2, 4];
+        let it = convert(items).filter_map(|&i| if i % 2 == 0 { Some(i) } else { None });
+        test(it, &[0, 2, 4])
+    }
+
+    #[test]
+    fn filter_map_mut() {
+        let items = [0u8, 1, 1, 2, 4];
+        let mut it = convert(items).filter_map(|&i| if i % 2 == 0 { Some(i) } else { None });
+        assert_eq!(it.next_mut(), Some(&mut 0));
+        *it.get_mut().unwrap() = 10;
+        assert_eq!(it.get(), Some(&10));
+        assert_eq!(it.next_mut(), Some(&mut 2));
+        assert_eq!(it.next(), Some(&4));
+        assert_eq!(it.next_mut(), None);
+
+        let it = convert(items).filter_map(|&i| if i % 2 == 0 { Some(i) } else { None });
+        let sum = it.fold_mut(0, |acc, i| {
+            *i += 1;
+            acc + *i
+        });
+        assert_eq!(sum, 9);
+    }
+
+    #[test]
+    fn filter_map_deref() {
+        let items = [0u8, 1, 1, 2, 4];
+        let it = convert(items).filter_map_deref(|&i| if i % 2 == 0 { Some(i) } else { None });
+        test_deref(it, &[0, 2, 4])
+    }
+
+    #[test]
+    fn find() {
+        let items = [0, 1];
+        let mut it = convert(items);
+        assert_eq!(it.clone().find(|&x| x % 2 == 1), Some(&1));
+        assert_eq!(it.find(|&x| x % 3 == 2), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn owned() {
+        let items = [0, 1];
+        let it = convert(items).owned();
+        assert_eq!(it.collect::<Vec<_>>(), items);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn owned_str() {
+        let s = "The quick brown fox jumps over the lazy dog";
+        let words = s.split_whitespace().map(str::to_owned).collect::<Vec<_>>();
+        let it = convert_ref(s.split_whitespace()).owned();
+        assert_eq!(it.collect::<Vec<_>>(), words);
+    }
+
+    #[test]
+    fn position() {
+        let items = [0, 1];
+        let mut it = convert(items);
+        assert_eq!(it.clone().position(|&x| x % 2 == 1), Some(1));
+        assert_eq!(it.position(|&x| x % 3 == 2), None);
+    }
+
+    #[test]
+    fn skip() {
+        let items = [0, 1, 2, 3];
+        let it = convert(items);
+        test(it.clone().skip(0), &[0, 1, 2, 3]);
+        test(it.clone().skip(2), &[2, 3]);
+        test(it.skip(5), &[]);
+    }
+
+    #[test]
+    fn skip_back() {
+        let it = convert([0, 1, 2, 3, 4]);
+        test_back(it.clone().skip(2), &[4, 3, 2]);
+        test_back(it.clone().skip(0), &[4, 3, 2, 1, 0]);
+        test_back(it.clone().skip(5), &[]);
+        test_back(it.clone().skip(7), &[]);
+
+        let mut skip = it.clone().skip(2);
+        assert_eq!(skip.next_back(), Some(&4));
+        assert_eq!(skip.next(), Some(&2));
+        assert_eq!(skip.next_back(), Some(&3));
+        assert_eq!(skip.next(), None);
+
+        let mut skip = it.clone().skip(1);
+        skip.advance_back();
+        assert_eq!(skip.rfold(0, |acc, &i| acc * 10 + i), 321);
+        assert_eq!(it.skip(3).rfold(0, |acc, &i| acc * 10 + i), 43);
+    }
+
+    #[test]
+    fn skip_while() {
+        let items = [0, 1, 2, 3];
+        let it = convert(items);
+        test(it.clone().skip_while(|&i| i < 0), &[0, 1, 2, 3]);
+        test(it.clone().skip_while(|&i| i < 2), &[2, 3]);
+        test(it.skip_while(|&i| i < 5), &[]);
+    }
+
+    #[test]
+    fn take() {
+        let items = [0, 1, 2, 3];
+        let it = convert(items);
+        test(it.clone().take(0), &[]);
+        test(it.clone().take(2), &[0, 1]);
+        test(it.take(5), &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn take_while() {
+        let items = [0, 1, 2, 3];
+        let it = convert(items);
+        test(it.clone().take_while(|&i| i < 0), &[]);
+        test(it.clone().take_while(|&i| i < 2), &[0, 1]);
+        test(it.take_while(|&i| i < 5), &[0, 1, 2, 3]);
+    }
+
+    fn _is_object_safe(_: &dyn StreamingIterator<Item = ()>) {}
+
+    fn _is_object_safe_mut(_: &dyn StreamingIteratorMut<Item = ()>) {}
+
+    #[test]
+    fn empty_iterator() {
+        let mut it: Empty<u8> = empty();
+
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn is_done_empty() {
+        let mut empty = empty::<u8>();
+        empty.advance();
+        assert!(empty.is_done());
+    }
+
+    #[test]
+    fn is_done_map() {
+        let items = [1];
+        let mut it = convert(items).map_ref::<u16, _>(|_| panic!("only called during get()"));
+        it.advance();
+        assert!(!it.is_done());
+        it.advance();
+        assert!(it.is_done());
+    }
+
+    #[test]
+    fn rev() {
+        let items = [0, 1, 2, 3];
+        let it = convert(items);
+        test(it.rev(), &[3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn fold() {
+        let items = [0, 1, 2, 3];
+        let it = convert(items);
+        assert_eq!(it.fold(0, |acc, i| acc * 10 + i), 123);
+    }
+
+    #[test]
+    fn for_each() {
+        let items = [0, 1, 2, 3];
+        let it = convert(items);
+        let mut acc = 0;
+        it.for_each(|i| acc = acc * 10 + i);
+        assert_eq!(acc, 123);
+    }
+
+    #[test]
+    fn for_each_indexed() {
+        let mut acc = 0;
+        convert([10, 20, 30]).for_each_indexed(|i, &x| acc += i * x);
+        assert_eq!(acc, 20 + 2 * 30);
+
+        let mut it = convert([5, 6, 7]);
+        it.advance();
+        let mut seen = [(0, 0); 2];
+        it.for_each_indexed(|i, &x| seen[i] = (i, x));
+        assert_eq!(seen, [(0, 6), (1, 7)]);
+    }
+
+    #[test]
+    fn rfold() {
+        let items = [0, 1, 2, 3];
+        let it = convert(items);
+        assert_eq!(it.rfold(0, |acc, i| acc * 10 + i), 3210);
+    }
+
+    #[test]
+    fn for_each_rev() {
+        let items = [0, 1, 2, 3];
+        let it = convert(items);
+        let mut acc = 0;
+        it.rev().for_each(|i| acc = acc * 10 + i);
+        assert_eq!(acc, 3210);
+    }
+
+    #[test]
+    fn for_each_mut() {
+        let mut items = [0, 1, 2, 3];
+
+        convert_mut(&mut items).for_each_mut(|i: &mut i32| *i += 10);
+        assert_eq!(items, [10, 11, 12, 13]);
+
+        // Note that filter sees a direct `&T` reference,
+        // rather than `&&mut T` like `Iterator` would.
+        convert_mut(&mut items)
+            .filter(|&i: &i32| i & 1 == 0)
+            .for_each_mut(|i: &mut i32| *i /= 2);
+        assert_eq!(items, [5, 11, 6, 13]);
+    }
+
+    #[test]
+    fn into_streaming_iter() {
+        let items = [0, 1, 2, 3];
+        let iter = items.into_streaming_iter();
+        test(iter, &items);
+        let iter = (&items).into_streaming_iter_ref();
+        test(iter, &items);
+        let mut mut_items = items;
+        let iter = (&mut mut_items).into_streaming_iter_mut();
+        test(iter, &items);
+    }
+
+    #[test]
+    fn zip_write() {
+        let mut out = [0; 3];
+        let mut it = convert([1, 2]).zip_write(&mut out);
+        while let Some((&i, slot)) = it.next_pair() {
+            *slot = i + 10;
+        }
+        assert_eq!(out, [11, 12, 0]);
+
+        let mut out = [0; 2];
+        let mut it = convert([1, 2, 3]).zip_write(&mut out);
+        assert_eq!(it.size_hint(), (2, Some(2)));
+        it.advance();
+        assert_eq!(it.get(), Some(&1));
+        *it.get_mut().unwrap() = 5;
+        it.advance();
+        *it.get_mut().unwrap() = 6;
+        it.advance();
+        assert_eq!(it.get(), None);
+        assert_eq!(it.get_mut(), None);
+        assert_eq!(out, [5, 6]);
+    }
+
+    #[test]
+    fn nth_owned() {
+        let items = [0, 1, 2, 3, 4];
+        let mut it = convert(items);
+        let third = it.nth_owned(2);
+        assert_eq!(third, Some(2));
+        assert_eq!(it.next(), Some(&3));
+        assert_eq!(it.nth_owned(0), Some(4));
+        assert_eq!(it.nth_owned(0), None);
+    }
+
+    #[test]
+    fn fold_ref() {
+        let items = [0, 1, 2, 3];
+        let mut it = convert(items);
+        let mut acc = [0; 2];
+        it.fold_ref(&mut acc, |acc, &i| acc[i % 2] += i);
+        assert_eq!(acc, [2, 4]);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn distinct_runs() {
+        let items = [1, 1, 2, 3, 3, 3, 1];
+        let mut it = convert(items).distinct_runs();
+        assert_eq!(it.next(), Some((1, 2)));
+        assert_eq!(it.next(), Some((2, 1)));
+        assert_eq!(it.next(), Some((3, 3)));
+        assert_eq!(it.next(), Some((1, 1)));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+
+        assert_eq!(empty::<i32>().distinct_runs().next(), None);
+        test_deref(once('a').distinct_runs(), &[('a', 1)]);
+    }
+
+    #[test]
+    fn split_once() {
+        let items = [3, 50, 101, 7, 200];
+        let mut it = convert(items);
+        assert!(it.split_once(|&i| i > 100));
+        test(it, &[7, 200]);
+
+        let mut it = convert(items);
+        assert!(!it.split_once(|&i| i > 1000));
+        assert_eq!(it.get(), None);
+    }
+
+    #[test]
+    fn retain_in_place() {
+        let mut items = [1, 2, 3, 4, 5, 6, 7];
+        let mut it = convert_mut(&mut items);
+        it.advance();
+        assert_eq!(it.retain_in_place(|&i| i % 2 == 1), 3);
+        assert_eq!(&items[..4], &[1, 3, 5, 7]);
+
+        let mut items = [1, 2];
+        assert_eq!(convert_mut(&mut items).retain_in_place(|_| false), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn transpose() {
+        let items = [0, 1, 2, 3, 4, 5, 6];
+        let it = convert(items).transpose(3);
+        assert_eq!(it.size_hint(), (6, Some(6)));
+        test(it, &[0, 3, 1, 4, 2, 5]);
+        test_back(convert(items).transpose(2), &[5, 3, 1, 4, 2, 0]);
+        test(convert(items).transpose(8), &[]);
+    }
+
+    #[test]
+    fn reduce_mut() {
+        let items = [[1, 5, 3], [4, 2, 6], [0, 9, 1]];
+        let max = convert(items).reduce_mut(|acc, row| {
+            for (a, &b) in acc.iter_mut().zip(row) {
+                *a = cmp::max(*a, b);
+            }
+        });
+        assert_eq!(max, Some([4, 9, 6]));
+        assert_eq!(empty::<[i32; 3]>().reduce_mut(|_, _| {}), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn chain_many() {
+        let iters = || alloc::vec![convert(0..2), convert(2..2), convert(2..3), convert(3..5)];
+        test(super::chain_many(iters()), &[0, 1, 2, 3, 4]);
+        test_back(super::chain_many(iters()), &[4, 3, 2, 1, 0]);
+        assert_eq!(
+            super::chain_many(iters()).fold(0, |acc, i| acc * 10 + i),
+            1234
+        );
+        assert_eq!(
+            super::chain_many(iters()).rfold(0, |acc, i| acc * 10 + i),
+            43210
+        );
+
+        let mut it = super::chain_many(iters());
+        assert_eq!(it.next(), Some(&0));
+        assert_eq!(it.next_back(), Some(&4));
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        assert_eq!(it.next_back(), Some(&3));
+        assert_eq!(it.next_back(), Some(&2));
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+
+        test(super::chain_many(Vec::<Empty<i32>>::new()), &[]);
+    }
+
+    #[test]
+    fn skip_convert_fast() {
+        // Both of these would take forever if `Skip` stepped through the skipped elements.
+        let it = convert(0..usize::MAX).skip(usize::MAX - 3);
+        assert_eq!(it.fold(0, |acc, &i| acc + (usize::MAX - i)), 1 + 2 + 3);
+
+        let mut it = convert(0..usize::MAX).skip(usize::MAX - 1);
+        assert_eq!(it.next(), Some(&(usize::MAX - 1)));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn chunks_exact() {
+        let items = [0, 1, 2, 3, 4, 5, 6];
+        let it = convert(items).chunks_exact(3);
+        assert_eq!(it.remainder_len(), 1);
+        assert_eq!(it.size_hint(), (2, Some(2)));
+        test(it.flatten(), &[0, 1, 2, 3, 4, 5]);
+
+        let mut it = convert(items).chunks_exact(2);
+        assert_eq!(it.next_mut().unwrap().next(), Some(&0));
+        assert_eq!(it.next_mut().unwrap().next(), Some(&2));
+        let chunk = it.next_mut().unwrap();
+        assert_eq!(chunk.next(), Some(&4));
+        assert_eq!(chunk.next(), Some(&5));
+        assert_eq!(chunk.next(), None);
+        assert!(it.next_mut().is_none());
+
+        let mut it = convert(items).chunks_exact(8);
+        assert_eq!(it.remainder_len(), 7);
+        assert!(it.next().is_none());
+
+        // Consumers which never look inside the chunks still step over them.
+        let mut n = 0;
+        convert(items).chunks_exact(2).for_each(|_| n += 1);
+        assert_eq!(n, 3);
+        let mut it = convert(items).chunks_exact(2);
+        assert!(it.nth(1).is_some());
+        assert_eq!(it.get_mut().unwrap().next(), Some(&2));
+    }
+
+    #[test]
+    fn chunks_exact_no_short_chunk() {
+        let mut it = convert([0, 1, 2, 3, 4, 5, 6]).chunks_exact(3);
+        assert_eq!(it.next_mut().unwrap().count(), 3);
+        // The second chunk is skipped without being consumed.
+        it.advance();
+        assert!(it.get().is_some());
+        assert!(it.next().is_none());
+        assert!(it.next().is_none());
+        assert_eq!(it.remainder_len(), 1);
+    }
+
+    #[test]
+    fn dedup() {
+        let items = [1, 1, 2, 3, 3];
+        test(convert(items).dedup(), &[1, 2, 3]);
+        test_back(convert(items).dedup(), &[3, 2, 1]);
+        assert_eq!(convert(items).dedup().fold(0, |acc, i| acc * 10 + i), 123);
+        assert_eq!(convert(items).dedup().rfold(0, |acc, i| acc * 10 + i), 321);
+
+        let mut it = convert(items).dedup();
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.next_back(), Some(&3));
+        assert_eq!(it.next(), Some(&2));
+        assert_eq!(it.next(), Some(&3));
+        assert_eq!(it.next(), None);
+
+        let items = [1, 1, 2, 3, 3, 3, 1];
+        test(convert(items).dedup(), &[1, 2, 3, 1]);
+        test_back(convert(items).dedup(), &[1, 3, 2, 1]);
+        test(empty::<i32>().dedup(), &[]);
+    }
+
+    #[test]
+    fn map_while() {
+        let items = ["1", "2", "x", "3"];
+        let it = convert(items).map_while(|s| s.parse::<i32>().ok());
+        test(it, &[1, 2]);
+    }
+
+    #[test]
+    fn take_while_inclusive() {
+        let items = [0, 1, 2, 3];
+        let it = convert(items);
+        test(it.clone().take_while_inclusive(|&i| i < 0), &[0]);
+        test(it.clone().take_while_inclusive(|&i| i < 2), &[0, 1, 2]);
+        test(it.take_while_inclusive(|&i| i < 5), &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn early_exit_size_hint() {
+        let mut count = 0;
+        let mut unbounded = from_fn(|| {
+            count += 1;
+            if count < 3 {
+                Some(count)
+            } else {
+                None
+            }
+        });
+        let mut it = unbounded.by_ref().take_while(|_| true);
+        assert_eq!(it.size_hint(), (0, None));
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.next(), Some(&2));
+        assert_eq!(it.size_hint(), (0, None));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.get(), None);
+        assert_eq!(it.size_hint(), (0, Some(0)));
+
+        let mut it = convert([1, 2, 3]).take_while(|&i| i < 2);
+        while it.next().is_some() {}
+        assert_eq!(it.size_hint(), (0, Some(0)));
+
+        let mut it = convert([1, 2, 3]).map_while(|&i| if i < 2 { Some(i) } else { None });
+        assert_eq!(it.size_hint(), (0, Some(3)));
+        while it.next().is_some() {}
+        assert_eq!(it.size_hint(), (0, Some(0)));
+
+        let mut it = convert([1, 2, 3]).take_while_inclusive(|&i| i < 2);
+        assert_eq!(it.size_hint(), (1, Some(3)));
+        while it.next().is_some() {}
+        assert_eq!(it.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn scan_with_state() {
+        let items = [1, 2, 3];
+        let mut it = convert(items).scan_with_state(0u8, |checksum, &i| {
+            *checksum ^= i;
+            i * 2
+        });
+        assert_eq!(it.next(), Some(&2));
+        assert_eq!(it.next(), Some(&4));
+        assert_eq!(it.next(), Some(&6));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.into_state(), 1 ^ 2 ^ 3);
+    }
+
+    #[test]
+    fn first() {
+        let mut it = convert([0, 1, 2]);
+        let first = it.first();
+        assert_eq!(first, Some(0));
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.first(), Some(2));
+        assert_eq!(it.first(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn join_debug() {
+        assert_eq!(convert([1, 2, 3]).join_debug(", "), "1, 2, 3");
+        assert_eq!(convert([Some(1)]).join_debug(", "), "Some(1)");
+        assert_eq!(empty::<u8>().join_debug(", "), "");
+    }
+
+    #[test]
+    fn skip_to() {
+        let items = [1, 2, 3, 5, 8, 13];
+
+        let mut it = convert_ref(&items);
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.skip_to(|&i| i < 5), Some(&5));
+        assert_eq!(it.next(), Some(&8));
+        assert_eq!(it.skip_to(|_| true), None);
+
+        let mut it = convert(items);
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.skip_to(|&i| i < 5), Some(&5));
+        assert_eq!(it.next(), Some(&8));
+        assert_eq!(it.skip_to(|_| true), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn fold_from_fn_and_successors() {
+        fn counter() -> impl StreamingIteratorMut<Item = u32> {
+            let mut n = 0;
+            from_fn(move || {
+                n += 1;
+                if n <= 5 {
+                    Some(n)
+                } else {
+                    None
+                }
+            })
+        }
+
+        fn powers() -> impl StreamingIteratorMut<Item = u32> {
+            successors(Some(1), |n| if n < 100 { Some(n * 3) } else { None })
+        }
+
+        fn default_fold<I: StreamingIterator<Item = u32>>(mut it: I) -> Vec<u32> {
+            let mut v = Vec::new();
+            while let Some(&i) = it.next() {
+                v.push(i);
+            }
+            v
+        }
+
+        fn fold<I: StreamingIterator<Item = u32>>(it: I) -> Vec<u32> {
+            it.fold(Vec::new(), |mut v, &i| {
+                v.push(i);
+                v
+            })
+        }
+
+        fn fold_mut<I: StreamingIteratorMut<Item = u32>>(it: I) -> Vec<u32> {
+            it.fold_mut(Vec::new(), |mut v, i| {
+                v.push(*i);
+                v
+            })
+        }
+
+        assert_eq!(fold(counter()), default_fold(counter()));
+        assert_eq!(fold_mut(counter()), default_fold(counter()));
+        assert_eq!(fold(powers()), default_fold(powers()));
+        assert_eq!(fold_mut(powers()), default_fold(powers()));
+
+        let mut it = counter();
+        it.advance();
+        assert_eq!(fold(it), [2, 3, 4, 5]);
+
+        let mut it = powers();
+        it.advance();
+        assert_eq!(fold(it), [3, 9, 27, 81, 243]);
+        let mut it = powers();
+        it.advance();
+        it.advance();
+        assert_eq!(fold_mut(it), [9, 27, 81, 243]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn collect_string() {
+        let chars = ['h', 'é', 'l', 'l', 'o'];
+        let s = convert(chars).collect_string();
+        assert_eq!(s, "héllo");
+        assert!(s.capacity() >= chars.len());
+        assert_eq!(empty::<char>().collect_string(), "");
+        // A huge upper bound must not be used as the initial capacity.
+        let it = convert(core::iter::repeat('a').take(usize::MAX)).take_while(|_| false);
+        assert_eq!(it.collect_string(), "");
+    }
+
+    #[test]
+    fn progress() {
+        let mut reports = [(0, None); 4];
+        let mut calls = 0;
+        let items = [0, 1, 2];
+        let it = convert(items).progress(|done, remaining| {
+            reports[calls] = (done, remaining);
+            calls += 1;
+        });
+        test(it, &items);
+        assert_eq!(calls, 3);
+        assert_eq!(reports[..3], [(1, Some(2)), (2, Some(1)), (3, Some(0))]);
+    }
+
+    #[test]
+    fn zip_mut() {
+        let mut a = [1, 2, 3, 4];
+        let mut b = [5, 6, 7];
+        let mut it = convert_mut(&mut a).zip_mut(convert_mut(&mut b));
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        while let Some((x, y)) = it.next_mut() {
+            core::mem::swap(x, y);
+        }
+        assert_eq!(it.get(), None);
+        assert_eq!(it.get_mut(), None);
+        assert_eq!(a, [5, 6, 7, 4]);
+        assert_eq!(b, [1, 2, 3]);
+
+        let mut a = [1, 2];
+        let mut b = [3, 4];
+        let it = convert_mut(&mut a).zip_mut(convert_mut(&mut b));
+        test(it, &[1, 2]);
+    }
+
+    #[test]
+    fn lookahead() {
+        let it = convert([0, 1, 2]).lookahead();
+        test(it, &[(0, Some(1)), (1, Some(2)), (2, None)]);
+
+        let mut it = convert([0, 1, 2]).lookahead();
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        it.advance();
+        assert_eq!(it.size_hint(), (2, Some(2)));
+        it.advance();
+        it.advance();
+        assert_eq!(it.size_hint(), (0, Some(0)));
+        assert_eq!(it.next(), None);
+
+        test(empty::<u8>().lookahead(), &[]);
+    }
+
+    #[test]
+    fn ref_iter() {
+        let items = [0, 1, 2, 3];
+        let mut it = convert_ref(&items);
+        assert_eq!(it.next(), Some(&0));
+        let refs = it.ref_iter();
+        test_deref(refs.copied(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn take_double_ended() {
+        let items = [0, 1, 2, 3, 4];
+        test_back(convert(items).take(3), &[2, 1, 0]);
+        test_back(convert(items).take(10), &[4, 3, 2, 1, 0]);
+        test_back(convert(items).take(0), &[]);
+
+        let mut it = convert(items).take(4);
+        assert_eq!(it.next(), Some(&0));
+        assert_eq!(it.next_back(), Some(&3));
+        assert_eq!(it.len(), 2);
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.next_back(), Some(&2));
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn take_double_ended_mut() {
+        let mut items = [1, 2, 3, 4, 5, 6];
+        let mut it = convert_mut(&mut items).take(4);
+        *it.next_mut().unwrap() *= 10;
+        *it.next_back_mut().unwrap() *= 10;
+        assert_eq!(it.len(), 2);
+        *it.next_back_mut().unwrap() += 100;
+        *it.next_back_mut().unwrap() += 100;
+        assert_eq!(it.next_back_mut(), None);
+        assert_eq!(items, [10, 102, 103, 40, 5, 6]);
+    }
+
+    #[test]
+    fn flat_map_ref() {
+        struct Group {
+            items: [u8; 3],
+            len: usize,
+        }
+
+        let groups = [
+            Group {
+                items: [0, 1, 2],
+                len: 2,
+            },
+            Group {
+                items: [0; 3],
+                len: 0,
+            },
+            Group {
+                items: [2, 3, 4],
+                len: 3,
+            },
+            Group {
+                items: [0; 3],
+                len: 0,
+            },
+        ];
+        let it = convert_ref(&groups).flat_map_ref(|g| &g.items[..g.len]);
+        test(it, &[0, 1, 2, 3, 4]);
+
+        let mut it = convert_ref(&groups).flat_map_ref(|g| &g.items[..g.len]);
+        assert_eq!(it.next(), Some(&0));
+        assert_eq!(it.size_hint(), (1, None));
+        let sum = it.fold(0, |acc, &i| acc + i);
+        assert_eq!(sum, 1 + 2 + 3 + 4);
+
+        let it = convert_ref(&groups[1..2]).flat_map_ref(|g| &g.items[..g.len]);
+        test(it, &[]);
+    }
+
+    #[test]
+    fn count_where() {
+        assert_eq!(convert(0..10).count_where(|&i| i % 2 == 0), 5);
+        assert_eq!(convert(0..10).count_where(|&i| i > 10), 0);
+        assert_eq!(empty::<u8>().count_where(|_| true), 0);
+    }
+
+    #[test]
+    fn min_max_by_key_with() {
+        let items = [(1, 'a'), (0, 'b'), (2, 'c'), (0, 'd'), (2, 'e')];
+        assert_eq!(
+            convert(items).min_by_key_with(|&(k, _)| k * 10),
+            Some((0, (0, 'b')))
+        );
+        assert_eq!(
+            convert(items).max_by_key_with(|&(k, _)| k * 10),
+            Some((20, (2, 'e')))
+        );
+        assert_eq!(empty::<u8>().min_by_key_with(|&i| i), None);
+        assert_eq!(empty::<u8>().max_by_key_with(|&i| i), None);
+    }
+
+    #[test]
+    fn step_by() {
+        let items = [0, 1, 2, 3, 4, 5, 6];
+        test(convert(items).step_by(1), &items);
+        test(convert(items).step_by(3), &[0, 3, 6]);
+        test(convert(items).step_by(10), &[0]);
+        test(empty::<u8>().step_by(2), &[]);
+
+        let mut it = convert(items).step_by(2);
+        assert_eq!(it.size_hint(), (4, Some(4)));
+        it.advance();
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        it.advance();
+        assert_eq!(it.size_hint(), (2, Some(2)));
+    }
+
+    #[test]
+    fn step_by_mut() {
+        let mut items = [1, 2, 3, 4, 5];
+        let mut it = convert_mut(&mut items).step_by(2);
+        while let Some(i) = it.next_mut() {
+            *i = 0;
+        }
+        assert_eq!(items, [0, 2, 0, 4, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn step_by_0() {
+        convert([0]).step_by(0);
+    }
+
+    #[test]
+    fn flatten_indexed() {
+        let mut items = [
+            convert_ref([].as_ref()),
+            convert_ref([1].as_ref()),
+            convert_ref([].as_ref()),
+            convert_ref([2, 3].as_ref()),
+            convert_ref([].as_ref()),
+        ];
+        test(convert_mut(&mut items).flatten_indexed(), &[1, 2, 3]);
+
+        let mut items = [
+            convert_ref([].as_ref()),
+            convert_ref([1].as_ref()),
+            convert_ref([].as_ref()),
+            convert_ref([2, 3].as_ref()),
+            convert_ref([].as_ref()),
+        ];
+        let mut it = convert_mut(&mut items).flatten_indexed();
+        assert_eq!(it.outer_index(), None);
+        assert_eq!(it.next_indexed(), Some((1, &1)));
+        assert_eq!(it.outer_index(), Some(1));
+        assert_eq!(it.next_indexed(), Some((3, &2)));
+        assert_eq!(it.next_indexed(), Some((3, &3)));
+        assert_eq!(it.get_indexed(), Some((3, &3)));
+        assert_eq!(it.next_indexed(), None);
+        assert_eq!(it.outer_index(), None);
+    }
+
+    #[test]
+    fn with_last() {
+        test(
+            convert([0, 1, 2]).with_last(),
+            &[(0, false), (1, false), (2, true)],
+        );
+        test(convert([0]).with_last(), &[(0, true)]);
+        test(empty::<u8>().with_last(), &[]);
+
+        let mut it = convert([0, 1, 2]).with_last();
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        it.advance();
+        assert_eq!(it.size_hint(), (2, Some(2)));
+        it.advance();
+        it.advance();
+        assert_eq!(it.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn enumerate() {
+        let items = [3, 4, 5];
+        test(convert(items).enumerate(), &items);
+
+        let mut it = convert(items).enumerate();
+        assert_eq!(it.index(), 0);
+        assert_eq!(it.next(), Some(&3));
+        assert_eq!(it.index(), 0);
+        assert_eq!(it.next(), Some(&4));
+        assert_eq!(it.index(), 1);
+        assert_eq!(it.size_hint(), (1, Some(1)));
+        assert_eq!(it.next(), Some(&5));
+        assert_eq!(it.index(), 2);
+        assert_eq!(it.next(), None);
+        assert_eq!(it.index(), 2);
+
+        let mut items = [0; 3];
+        let mut it = convert_mut(&mut items).enumerate();
+        while it.next().is_some() {
+            let index = it.index();
+            *it.get_mut().unwrap() = index * 10;
+        }
+        assert_eq!(items, [0, 10, 20]);
+    }
+
+    #[test]
+    fn peekable() {
+        let items = [0, 1, 2, 3];
+        test(convert(items).peekable(), &items);
+
+        let mut it = convert(items).peekable();
+        assert_eq!(it.size_hint(), (4, Some(4)));
+        assert_eq!(it.peek(), Some(&0));
+        assert_eq!(it.size_hint(), (4, Some(4)));
         assert_eq!(it.get(), None);
+        assert!(it.is_done());
+        assert_eq!(it.next(), Some(&0));
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.peek(), Some(&2));
+        assert_eq!(it.fold(0, |acc, i| acc * 10 + i), 23);
+
+        let mut it = convert([0]).peekable();
+        it.advance();
+        assert_eq!(it.peek(), None);
+        assert_eq!(it.size_hint(), (0, Some(0)));
+        assert!(it.is_done());
+        assert_eq!(it.next(), None);
+
+        let mut items = [0, 1];
+        let mut it = convert_mut(&mut items).peekable();
+        *it.peek_mut().unwrap() = 5;
+        assert_eq!(it.next(), Some(&5));
+        *it.peek_mut().unwrap() = 6;
+        assert_eq!(it.next_mut(), Some(&mut 6));
     }
 
-    fn test_back<I>(mut it: I, expected: &[I::Item])
-    where
-        I: DoubleEndedStreamingIterator,
-        I::Item: Sized + PartialEq + Debug,
-    {
-        for item in expected {
-            it.advance_back();
-            assert_eq!(it.get(), Some(item));
-            assert_eq!(it.get(), Some(item));
-        }
-        it.advance_back();
-        assert_eq!(it.get(), None);
-        assert_eq!(it.get(), None);
+    #[test]
+    fn min_max() {
+        assert_eq!(convert([3, 1, 2]).max(), Some(3));
+        assert_eq!(convert([3, 1, 2]).min(), Some(1));
+        assert_eq!(empty::<u8>().max(), None);
+        assert_eq!(empty::<u8>().min(), None);
     }
 
-    fn test_deref<I>(mut it: I, expected: &[I::Item])
-    where
-        I: Iterator,
-        I::Item: Sized + PartialEq + Debug,
-    {
-        for item in expected {
-            assert_eq!(it.next().as_ref(), Some(item));
+    #[test]
+    fn min_max_by() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Point {
+            x: i32,
+            name: char,
         }
-        assert_eq!(it.next(), None)
+
+        let points = [
+            Point { x: 2, name: 'a' },
+            Point { x: 1, name: 'b' },
+            Point { x: 2, name: 'c' },
+            Point { x: 1, name: 'd' },
+        ];
+        let max = convert_ref(&points).max_by(|a, b| a.x.cmp(&b.x));
+        assert_eq!(max.map(|p| p.name), Some('c'));
+        let min = convert_ref(&points).min_by(|a, b| a.x.cmp(&b.x));
+        assert_eq!(min.map(|p| p.name), Some('b'));
+        assert_eq!(empty::<Point>().max_by(|a, b| a.x.cmp(&b.x)), None);
     }
 
     #[test]
-    fn all() {
-        let items = [0, 1, 2];
-        let mut it = convert(items);
-        assert!(it.clone().all(|&i| i < 3));
-        assert!(!it.all(|&i| i % 2 == 0));
+    fn sum_by() {
+        let words = ["a", "bb", "", "cccc"];
+        assert_eq!(convert(words).sum_by(|w| w.len()), 7);
+        assert_eq!(convert([1.5, 2.0]).sum_by(|&x| x * 2.0), 7.0);
+        assert_eq!(empty::<&str>().sum_by(|w| w.len()), 0);
     }
 
     #[test]
-    fn any() {
-        let items = [0, 1, 2];
-        let mut it = convert(items);
-        assert!(it.clone().any(|&i| i > 1));
-        assert!(!it.any(|&i| i > 2));
+    fn last() {
+        assert_eq!(convert([1, 2, 3]).last(), Some(3));
+        assert_eq!(empty::<u8>().last(), None);
+        let it = successors(Some(1), |n| if n < 100 { Some(n * 2) } else { None });
+        assert_eq!(it.last(), Some(128));
     }
 
     #[test]
-    fn test_chain() {
-        let items_a = [0, 1, 2, 3];
-        let items_b = [10, 20, 30];
-        let expected = [0, 1, 2, 3, 10, 20, 30];
-
-        let it = convert(items_a).chain(convert(items_b));
-        test(it, &expected);
+    fn reduce() {
+        let max = convert([3, 7, 2]).reduce(|acc, &i| cmp::max(acc, i));
+        assert_eq!(max, Some(7));
+        assert_eq!(convert([3]).reduce(|_, _| unreachable!()), Some(3));
+        assert_eq!(empty::<i32>().reduce(|acc, &i| acc + i), None);
     }
 
     #[test]
-    fn test_chain_back() {
-        let items_a = [0, 1, 2, 3];
-        let items_b = [10, 20, 30];
-        let expected = [30, 20, 10, 3, 2, 1, 0];
+    fn try_fold() {
+        let items = [1, 2, -3, 4, -5, 6];
+        let mut it = convert(items);
+        let sum = it.try_fold(0, |sum, &i| if i < 0 { Err(i) } else { Ok(sum + i) });
+        assert_eq!(sum, Err(-3));
+        assert_eq!(it.get(), Some(&-3));
+        let sum = it.try_fold(0, |sum, &i| if i < 0 { Err(i) } else { Ok(sum + i) });
+        assert_eq!(sum, Err(-5));
+        let sum = it.try_fold(0, |sum, &i| if i < 0 { Err(i) } else { Ok(sum + i) });
+        assert_eq!(sum, Ok(6));
+        assert_eq!(it.get(), None);
 
-        let it = convert(items_a).chain(convert(items_b));
-        test_back(it, &expected);
+        let mut calls = 0;
+        let mut it = convert(items);
+        let res = it.try_for_each(|&i| {
+            calls += 1;
+            if i < 0 {
+                Err(i)
+            } else {
+                Ok(())
+            }
+        });
+        assert_eq!(res, Err(-3));
+        assert_eq!(calls, 3);
+        assert_eq!(it.next(), Some(&4));
     }
 
     #[test]
-    fn test_chain_mixed() {
-        let items_a = [0, 1, 2, 3];
-        let items_b = [10, 20, 30];
+    fn find_map() {
+        let mut it = convert(["a", "12", "x"]);
+        assert_eq!(it.find_map(|s| s.parse::<i32>().ok()), Some(12));
+        assert_eq!(it.get(), Some(&"12"));
+        assert_eq!(it.find_map(|s| s.parse::<i32>().ok()), None);
+    }
 
-        let mut it = convert(items_a).chain(convert(items_b));
+    #[test]
+    fn cycle() {
+        let it = convert([1, 2, 3]).cycle();
+        assert_eq!(it.size_hint(), (usize::MAX, None));
+        test(it.take(7), &[1, 2, 3, 1, 2, 3, 1]);
 
-        assert_eq!(it.get(), None);
-        it.advance();
-        assert_eq!(it.get().copied(), Some(0));
-        it.advance_back();
-        assert_eq!(it.get().copied(), Some(30));
-        it.advance();
-        assert_eq!(it.get().copied(), Some(1));
-        it.advance_back();
-        assert_eq!(it.get().copied(), Some(20));
-        it.advance();
-        assert_eq!(it.get().copied(), Some(2));
-        it.advance_back();
-        assert_eq!(it.get().copied(), Some(10));
-        it.advance_back();
-        assert_eq!(it.get().copied(), Some(3));
+        let mut it = empty::<u8>().cycle();
+        assert_eq!(it.size_hint(), (0, Some(0)));
+        assert_eq!(it.next(), None);
     }
 
     #[test]
-    fn cloned() {
-        let items = [0, 1];
-        let mut it = convert(items).cloned();
-        assert_eq!(it.next(), Some(0));
-        assert_eq!(it.next(), Some(1));
-        assert_eq!(it.next(), None);
+    fn dedup_by_key() {
+        let items = [1i32, -1, 2, -2, 2, 3, -1];
+        test(convert(items).dedup_by_key(|i| i.abs()), &[1, 2, 3, -1]);
+        test_back(convert(items).dedup_by_key(|i| i.abs()), &[-1, 3, 2, -1]);
+        let sum = convert(items)
+            .dedup_by_key(|i| i.abs())
+            .fold(0, |acc, i| acc + i);
+        assert_eq!(sum, 5);
+
+        struct NotClone(i32);
+        let items = [NotClone(1), NotClone(1), NotClone(2)];
+        let it = convert_ref(&items).dedup_by_key(|n| n.0).map_ref(|n| &n.0);
+        test(it, &[1, 2]);
     }
 
     #[test]
-    fn copied() {
-        let items = [0, 1];
-        let mut it = convert(items).copied();
-        assert_eq!(it.next(), Some(0));
-        assert_eq!(it.next(), Some(1));
-        assert_eq!(it.next(), None);
+    fn dedup_by() {
+        let items = ["apple", "avocado", "banana", "blueberry", "apricot"];
+        let same = |a: &&str, b: &&str| a.chars().next() == b.chars().next();
+        test(
+            convert(items).dedup_by(same),
+            &["apple", "banana", "apricot"],
+        );
+        test_back(
+            convert(items).dedup_by(same),
+            &["apricot", "blueberry", "avocado"],
+        );
+        let count = convert(items).dedup_by(same).rfold(0, |acc, _| acc + 1);
+        assert_eq!(count, 3);
     }
 
     #[test]
-    fn test_convert() {
-        let items = [0, 1];
-        let it = convert(items);
-        test(it, &items);
+    fn chunk_by() {
+        let items = [1, 1, 2, 2, 2, 3];
+        let mut it = convert(items).chunk_by(|&i| i);
+        let mut lens = [0; 3];
+        let mut n = 0;
+        while let Some(group) = it.next_mut() {
+            lens[n] = group.count();
+            n += 1;
+        }
+        assert_eq!(lens, [2, 3, 1]);
+        assert_eq!(n, 3);
+
+        let mut it = convert(items).chunk_by(|&i| i);
+        assert_eq!(it.next_mut().unwrap().next(), Some(&1));
+        let group = it.next_mut().unwrap();
+        assert_eq!(group.size_hint(), (1, Some(4)));
+        test(group, &[2, 2, 2]);
+        let group = it.next_mut().unwrap();
+        test(group, &[3]);
+        assert!(it.next_mut().is_none());
+
+        test(convert(items).chunk_by(|&i| i % 2).flatten(), &items);
+        assert!(empty::<i32>().chunk_by(|&i| i).next().is_none());
     }
 
     #[test]
-    fn test_convert_ref() {
-        let items = [&0, &1];
-        let it = convert_ref(items.iter());
-        test(it, &items);
+    fn exact_size() {
+        fn check<I: ExactSizeStreamingIterator>(mut it: I) {
+            loop {
+                let len = it.len();
+                assert_eq!(it.is_empty(), len == 0);
+                it.advance();
+                if it.is_done() {
+                    assert_eq!(len, 0);
+                    break;
+                }
+                assert_eq!(it.len(), len - 1);
+            }
+        }
+
+        let items = [0, 1, 2, 3, 4];
+        assert_eq!(convert(items).len(), 5);
+        check(convert(items));
+        check(convert_ref(&items));
+        check(convert_mut(&mut [0, 1, 2]));
+        check(empty::<u8>());
+        check(once(1));
+        check(once_with(|| 1));
+        check(convert(items).take(3));
+        check(convert(items).take(10));
+        check(convert(items).skip(2));
+        check(convert(items).skip(10));
+        check(windows_mut(&mut [0; 5], 2));
+        assert_eq!(convert(items).skip(2).len(), 3);
+        assert_eq!(convert(items).take(2).len(), 2);
+        assert!(empty::<u8>().is_empty());
     }
 
     #[test]
-    fn count() {
-        let items = [0, 1, 2, 3];
-        let it = convert(items);
-        assert_eq!(it.count(), 4);
+    fn clone_closure_adapters() {
+        let items = [0, 1, 2, 3, 4, 5];
+        let mut it = convert(items).filter(|&i| i % 2 == 0).map(|&i| i * 10);
+        it.advance();
+        assert_eq!(it.get(), Some(&0));
+        let copy = it.clone();
+        test(it, &[20, 40]);
+        test(copy, &[20, 40]);
+
+        let it = convert(items).take_while(|&i| i < 3).inspect(|_| ());
+        test(it.clone(), &[0, 1, 2]);
+        test(it, &[0, 1, 2]);
     }
 
     #[test]
-    fn filter() {
-        let items = [0, 1, 2, 3];
-        let it = convert(items).filter(|x| x % 2 == 0);
-        test(it, &[0, 2]);
+    #[cfg(feature = "alloc")]
+    fn unzip_deref() {
+        let (nums, chars): (Vec<i32>, Vec<char>) =
+            convert([(1, 'a'), (2, 'b')]).unzip_deref(|&(n, _)| n, |&(_, c)| c);
+        assert_eq!(nums, [1, 2]);
+        assert_eq!(chars, ['a', 'b']);
+
+        let (nums, chars): (Vec<i32>, String) =
+            empty::<(i32, char)>().unzip_deref(|p| p.0, |p| p.1);
+        assert!(nums.is_empty());
+        assert!(chars.is_empty());
     }
 
     #[test]
-    fn fuse() {
-        struct Flicker(i32);
+    #[cfg(feature = "alloc")]
+    fn partition_deref() {
+        let (even, odd): (Vec<i32>, Vec<i32>) =
+            convert([1, 2, 3, 4]).partition_deref(|&i| i % 2 == 0);
+        assert_eq!(even, [2, 4]);
+        assert_eq!(odd, [1, 3]);
 
-        impl StreamingIterator for Flicker {
-            type Item = i32;
+        let words = ["apple", "kiwi", "banana"];
+        let (long, short): (Vec<String>, Vec<String>) =
+            convert_ref(words.iter().copied()).partition_deref(|s| s.len() > 4);
+        assert_eq!(long, ["apple", "banana"]);
+        assert_eq!(short, ["kiwi"]);
+    }
 
-            fn advance(&mut self) {
-                self.0 += 1;
-            }
+    #[test]
+    fn zip_map() {
+        let it = convert([1, 2, 3]).zip_map(convert([10, 20, 30, 40]), |&a, &b| a + b);
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        test(it, &[11, 22, 33]);
 
-            fn get(&self) -> Option<&i32> {
-                if self.0 % 4 == 3 {
-                    None
-                } else {
-                    Some(&self.0)
-                }
+        let it = convert([1, 2, 3]).zip_map(empty::<i32>(), |&a, &b| a + b);
+        test(it, &[]);
+
+        let mut it = convert(["a", "b"]).zip_map(convert([1, 2]), |&s, &n| s.len() + n);
+        assert_eq!(it.next_mut(), Some(&mut 2));
+        *it.get_mut().unwrap() += 10;
+        assert_eq!(it.get(), Some(&12));
+    }
+
+    #[test]
+    fn flat_map_mut() {
+        let mut a = [1, 2];
+        let mut b: [i32; 0] = [];
+        let mut c = [3];
+        let mut slices = [&mut a[..], &mut b[..], &mut c[..]];
+        {
+            let mut it = convert_mut(&mut slices).flat_map_mut(|s| convert_mut(core::mem::take(s)));
+            while let Some(i) = it.next_mut() {
+                *i *= 10;
             }
         }
+        assert_eq!(a, [10, 20]);
+        assert_eq!(c, [30]);
+
+        let mut slices = [&mut a[..], &mut b[..], &mut c[..]];
+        let sum = convert_mut(&mut slices)
+            .flat_map_mut(|s| convert_mut(core::mem::take(s)))
+            .fold_mut(0, |acc, i| {
+                *i += 1;
+                acc + *i
+            });
+        assert_eq!(sum, 63);
+        assert_eq!(a, [11, 21]);
+        assert_eq!(c, [31]);
 
-        let mut it = Flicker(0).fuse();
-        assert_eq!(it.get(), None);
-        it.advance();
-        assert_eq!(it.get(), Some(&1));
+        let items = [[0, 1], [2, 3]];
+        let it = convert(items).flat_map_mut(|&mut i| convert(i));
+        test(it, &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn advance_by() {
+        let mut it = convert([0, 1, 2]);
+        assert_eq!(it.advance_by(0), Ok(()));
+        assert_eq!(it.advance_by(2), Ok(()));
         assert_eq!(it.get(), Some(&1));
-        it.advance();
+        assert_eq!(it.advance_by(1), Ok(()));
         assert_eq!(it.get(), Some(&2));
-        assert_eq!(it.get(), Some(&2));
-        it.advance();
-        assert_eq!(it.get(), None);
-        assert_eq!(it.get(), None);
-        it.advance();
-        assert_eq!(it.get(), None);
+        assert_eq!(it.advance_by(1), Err(1));
+        assert!(it.is_done());
+
+        let mut it = convert([0, 1, 2]);
+        assert_eq!(it.advance_by(5), Err(2));
         assert_eq!(it.get(), None);
+
+        // Without an exact length, `Convert` has to step through the elements.
+        let mut it = convert([0, 1, 2, 3].iter().filter(|&&i| i != 1));
+        assert_eq!(it.advance_by(2), Ok(()));
+        assert_eq!(it.get(), Some(&&2));
+        assert_eq!(it.advance_by(4), Err(3));
+
+        // The default implementation.
+        let mut it = convert([0, 1, 2]).filter(|_| true);
+        assert_eq!(it.advance_by(3), Ok(()));
+        assert_eq!(it.get(), Some(&2));
+        let mut it = convert([0, 1, 2]).filter(|_| true);
+        assert_eq!(it.advance_by(4), Err(1));
     }
 
     #[test]
-    fn inspect() {
-        let items = [0, 1, 2, 3];
-        let mut idx = 0;
-        let mut items_inspected = [-1, -1, -1, -1];
+    fn advance_back_by() {
+        let mut it = convert([0, 1, 2]);
+        assert_eq!(it.advance_back_by(2), Ok(()));
+        assert_eq!(it.get(), Some(&1));
+        assert_eq!(it.advance_back_by(3), Err(2));
 
-        {
-            let it = convert(items).inspect(|&i| {
-                items_inspected[idx] = i;
-                idx += 1;
-            });
+        let mut it = convert([0, 1, 2]).filter(|_| true);
+        assert_eq!(it.advance_back_by(1), Ok(()));
+        assert_eq!(it.get(), Some(&2));
+        assert_eq!(it.advance_back_by(4), Err(2));
+    }
 
-            test(it, &items);
+    #[test]
+    fn repeat_n() {
+        let it = crate::repeat_n(7, 3);
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        assert_eq!(it.len(), 3);
+        assert_eq!(it.clone().count(), 3);
+        test(it.clone(), &[7, 7, 7]);
+        test_back(it, &[7, 7, 7]);
+
+        test(crate::repeat_n(7, 0), &[]);
+
+        let mut it = crate::repeat_n(0, 3);
+        let mut seen = 0;
+        while let Some(i) = it.next_mut() {
+            *i += 1;
+            seen += 1;
         }
+        assert_eq!(seen, 3);
+        assert_eq!(it.len(), 0);
 
-        assert_eq!(&items_inspected, &items);
+        let mut it = crate::repeat_n(1, 3);
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.next_back(), Some(&1));
+        assert_eq!(it.size_hint(), (1, Some(1)));
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.next_back(), None);
     }
 
     #[test]
-    fn map() {
-        let items = [0, 1];
-        let it = convert(items.iter().map(|&i| i as usize)).map(|&i| i as i32);
-        test(it, &items);
+    fn iterate() {
+        let it = crate::iterate(1u32, |i| i * 2);
+        assert_eq!(it.size_hint(), (usize::MAX, None));
+        test(it.take(5), &[1, 2, 4, 8, 16]);
+
+        let mut it = crate::iterate(1u32, |i| i + 1);
+        assert_eq!(it.get(), None);
+        *it.next_mut().unwrap() = 10;
+        assert_eq!(it.next(), Some(&11));
     }
 
     #[test]
-    fn map_deref() {
-        let items = [0, 1];
-        let it = convert(items.iter().map(|&i| i as usize)).map_deref(|&i| i as i32);
-        test_deref(it, &items);
+    fn convert_into_ref() {
+        let it = crate::convert_into_ref([0, 1, 2, 3]);
+        assert_eq!(it.size_hint(), (4, Some(4)));
+        test(it.clone(), &[0, 1, 2, 3]);
+        test_back(it, &[3, 2, 1, 0]);
+
+        let mut it = crate::convert_into_ref([0, 1, 2, 3]);
+        assert_eq!(it.next(), Some(&0));
+        assert_eq!(it.next_back(), Some(&3));
+        assert_eq!(it.len(), 2);
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.next_back(), Some(&2));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
     }
 
     #[test]
-    fn map_deref_mut() {
-        let mut items = [1, 2, 3];
-        {
-            let it = convert_mut(&mut items).map_deref_mut(|i| -core::mem::replace(i, 0));
-            test_deref(it, &[-1, -2, -3]);
+    #[cfg(feature = "alloc")]
+    fn convert_into_ref_vec() {
+        fn owned() -> impl DoubleEndedStreamingIterator<Item = String> {
+            let names = ["a", "b", "c"];
+            crate::convert_into_ref(names.iter().map(|&s| String::from(s)).collect::<Vec<_>>())
         }
-        assert_eq!(items, [0, 0, 0]);
+
+        assert_eq!(owned().fold(String::new(), |acc, s| acc + s), "abc");
+        assert_eq!(owned().rfold(String::new(), |acc, s| acc + s), "cba");
+        assert_eq!(crate::convert_into_ref(Vec::<u8>::new()).next(), None);
     }
 
     #[test]
-    fn map_ref() {
-        #[derive(Clone)]
-        struct Foo(i32);
+    fn successors_n() {
+        let mut it = crate::successors_n(Some(1), 4, |i| Some(i * 2));
+        assert_eq!(it.size_hint(), (1, Some(4)));
+        for (i, expected) in [1, 2, 4, 8].iter().enumerate() {
+            assert_eq!(it.next(), Some(expected));
+            assert_eq!(it.size_hint(), (0, Some(3 - i)));
+        }
+        assert_eq!(it.next(), None);
+        assert_eq!(it.size_hint(), (0, Some(0)));
+
+        test(
+            crate::successors_n(Some(1), 4, |i| Some(i * 2)),
+            &[1, 2, 4, 8],
+        );
+        test(crate::successors_n(Some(1), 0, |i| Some(i * 2)), &[]);
+        test(crate::successors_n(None, 4, |i: i32| Some(i * 2)), &[]);
+
+        let mut it = crate::successors_n(Some(1), 4, |i| if i < 2 { Some(i + 1) } else { None });
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.next(), Some(&2));
+        assert_eq!(it.size_hint(), (0, Some(2)));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.size_hint(), (0, Some(0)));
+    }
 
-        let items = [Foo(0), Foo(1)];
-        let it = convert(items).map_ref(|f| &f.0);
-        test(it, &[0, 1]);
+    #[test]
+    fn range() {
+        let it = crate::range(0u32, 4);
+        assert_eq!(it.size_hint(), (4, Some(4)));
+        assert_eq!(it.clone().count(), 4);
+        test(it.clone(), &[0, 1, 2, 3]);
+        test_back(it, &[3, 2, 1, 0]);
+
+        test(crate::range(-2i8, 2), &[-2, -1, 0, 1]);
+        test(crate::range(i8::MIN, i8::MIN + 2), &[i8::MIN, i8::MIN + 1]);
+        test_back(
+            crate::range(u8::MAX - 2, u8::MAX),
+            &[u8::MAX - 1, u8::MAX - 2],
+        );
+        assert_eq!(crate::range(i8::MIN, i8::MAX).count(), 255);
+
+        test(crate::range(3, 3), &[]);
+        test(crate::range(5, 3), &[]);
+        assert_eq!(crate::range(5, 3).size_hint(), (0, Some(0)));
+        test_back(crate::range(5usize, 3), &[]);
+
+        let mut it = crate::range(0, 4);
+        assert_eq!(it.next(), Some(&0));
+        assert_eq!(it.next_back(), Some(&3));
+        assert_eq!(it.size_hint(), (2, Some(2)));
+        assert_eq!(it.next_back(), Some(&2));
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
     }
 
     #[test]
-    fn flat_map() {
-        let items = [[0, 1, 2], [3, 4, 5]];
-        let it = convert(items).flat_map(|&i| convert(i));
+    fn eq() {
+        let items = [0, 1, 2];
+        assert!(convert(items).eq(convert(items)));
+        assert!(convert(items).eq(convert_ref(&items)));
+        assert!(!convert(items).ne(convert(items)));
+        assert!(empty::<i32>().eq(empty::<i32>()));
 
-        test(it, &[0, 1, 2, 3, 4, 5]);
+        assert!(!convert(items).eq(convert([0, 1])));
+        assert!(!convert([0, 1]).eq(convert(items)));
+        assert!(convert([0, 1]).ne(convert(items)));
+        assert!(!convert(items).eq(convert([0, 5, 2])));
+        assert!(!convert(items).eq(empty::<i32>()));
+
+        assert!(convert_ref(["ab", "c"]).eq(convert_ref(["ab", "c"])));
+        assert!(convert_ref(["ab", "c"]).ne(convert_ref(["a", "bc"])));
     }
 
     #[test]
-    fn flatten() {
-        let mut items = [
-            convert_ref([].as_ref()),
-            convert_ref([1].as_ref()),
-            convert_ref([].as_ref()),
-            convert_ref([2, 3].as_ref()),
-            convert_ref([].as_ref()),
-        ];
-        let it = convert_mut(&mut items).flatten();
+    fn cmp() {
+        use core::cmp::Ordering;
+
+        assert_eq!(convert([1, 2]).cmp(convert([1, 2])), Ordering::Equal);
+        assert_eq!(convert([1, 2]).cmp(convert([1, 2, 0])), Ordering::Less);
+        assert_eq!(convert([1, 2, 0]).cmp(convert([1, 2])), Ordering::Greater);
+        assert_eq!(convert([1, 3]).cmp(convert([2, 0, 0])), Ordering::Less);
+        assert_eq!(convert([2]).cmp(convert([1, 9])), Ordering::Greater);
+        assert_eq!(empty::<i32>().cmp(empty()), Ordering::Equal);
+    }
+
+    #[test]
+    fn partial_cmp() {
+        use core::cmp::Ordering;
+
+        let nan = f64::NAN;
+        assert_eq!(
+            convert([1.0, 2.0]).partial_cmp(convert([1.0, 2.0])),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            convert([1.0]).partial_cmp(convert([1.0, 2.0])),
+            Some(Ordering::Less)
+        );
+        assert_eq!(convert([1.0, nan]).partial_cmp(convert([1.0, 2.0])), None);
+        assert_eq!(
+            convert([0.0, nan]).partial_cmp(convert([1.0, 2.0])),
+            Some(Ordering::Less)
+        );
+
+        assert!(convert([1, 2]).lt(convert([1, 3])));
+        assert!(convert([1, 2]).lt(convert([1, 2, 3])));
+        assert!(!convert([1, 2]).lt(convert([1, 2])));
+        assert!(convert([1, 2]).le(convert([1, 2])));
+        assert!(!convert([1, 3]).le(convert([1, 2])));
+        assert!(convert([1, 3]).gt(convert([1, 2, 5])));
+        assert!(!convert([1, 2]).gt(convert([1, 2])));
+        assert!(convert([1, 2]).ge(convert([1, 2])));
+        assert!(!convert([1]).ge(convert([1, 2])));
+
+        assert!(!convert([nan]).lt(convert([1.0])));
+        assert!(!convert([nan]).ge(convert([1.0])));
+    }
+
+    #[test]
+    fn scan() {
+        let it = convert([1, 2, 3]).scan(0, |sum, &i| {
+            *sum += i;
+            Some(*sum)
+        });
+        test(it, &[1, 3, 6]);
+
+        let mut calls = 0;
+        let mut it = convert([1, 2, 3, 4]).scan(0, |sum, &i| {
+            calls += 1;
+            *sum += i;
+            if *sum > 3 {
+                None
+            } else {
+                Some(*sum * 10)
+            }
+        });
+        assert_eq!(it.next(), Some(&10));
+        assert_eq!(it.next(), Some(&30));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.size_hint(), (0, Some(0)));
+        assert_eq!(it.next(), None);
+        drop(it);
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn fold_while() {
+        let cap = |sum: i32, &i: &i32| {
+            if sum + i > 10 {
+                FoldWhile::Done(sum)
+            } else {
+                FoldWhile::Continue(sum + i)
+            }
+        };
+
+        let mut it = convert([3, 4, 5, 6]);
+        assert_eq!(it.fold_while(0, cap), 7);
+        assert_eq!(it.get(), Some(&5));
+        test(it, &[6]);
+
+        let mut it = convert([1, 2, 3]);
+        assert_eq!(it.fold_while(0, cap), 6);
+        assert!(it.is_done());
+
+        let mut it = convert([1, 2, 3]);
+        assert_eq!(it.fold_while(0, |_, &i| FoldWhile::Done(i)), 1);
+        assert_eq!(it.next(), Some(&2));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn collect() {
+        use alloc::collections::BTreeSet;
+
+        let v: Vec<i32> = convert([3, 1, 2]).collect();
+        assert_eq!(v, [3, 1, 2]);
+
+        let words: Vec<String> = convert_ref(["b", "a", "b"]).collect();
+        assert_eq!(words, ["b", "a", "b"]);
+
+        let set: BTreeSet<String> = convert_ref(["b", "a", "b"]).collect();
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), ["a", "b"]);
+
+        let v: Vec<i32> = empty::<i32>().collect();
+        assert!(v.is_empty());
+    }
 
-        test(it, &[1, 2, 3]);
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec() {
+        assert_eq!(convert([1, 2, 3]).to_vec(), [1, 2, 3]);
+        assert_eq!(convert_ref(["a", "bc"]).to_vec(), ["a", "bc"]);
+        assert!(empty::<u8>().to_vec().is_empty());
     }
 
     #[test]
-    fn flatten_unsized() {
-        type DynI32 = dyn StreamingIterator<Item = i32>;
-        let mut items = [
-            &mut once(1) as &mut DynI32,
-            &mut empty(),
-            &mut convert(2..=3),
-        ];
-        let iters = items.iter_mut().map(|iter| &mut **iter);
-        let it = convert_mut(iters).flatten();
+    #[cfg(feature = "alloc")]
+    fn counts_by() {
+        let text = "the quick brown fox jumps over the lazy dog";
+        let counts = convert_ref(text.split(' ')).counts_by(|word| word.len());
+        assert_eq!(
+            counts.into_iter().collect::<Vec<_>>(),
+            [(3, 4), (4, 2), (5, 3)]
+        );
 
-        test(it, &[1, 2, 3]);
+        assert!(empty::<u8>().counts_by(|&b| b).is_empty());
     }
 
     #[test]
-    fn nth() {
-        let items = [0, 1];
-        let mut it = convert(items);
-        assert_eq!(it.clone().nth(0), Some(&0));
-        assert_eq!(it.clone().nth(1), Some(&1));
-        assert_eq!(it.nth(2), None);
+    #[cfg(feature = "alloc")]
+    fn extend() {
+        let mut v = Vec::from([0]);
+        crate::extend(&mut v, convert([1, 2]));
+        assert_eq!(v, [0, 1, 2]);
+
+        crate::extend(&mut v, empty::<i32>());
+        assert_eq!(v, [0, 1, 2]);
+
+        let mut s = String::from("ab");
+        crate::extend(&mut s, convert(['c', 'd']));
+        assert_eq!(s, "abcd");
     }
 
     #[test]
-    fn filter_map() {
-        let items = [0u8, 1, 1, 2, 4];
-        let it = convert(items).filter_map(|&i| if i % 2 == 0 { Some(i) } else { None });
-        test(it, &[0, 2, 4])
+    fn successors_bounded() {
+        let mut it = crate::successors_bounded(Some(1), 4, |i| Some(i * 2));
+        assert_eq!(it.size_hint(), (1, Some(4)));
+        for (i, expected) in [1, 2, 4, 8].iter().enumerate() {
+            assert_eq!(it.next(), Some(expected));
+            assert_eq!(it.size_hint(), (0, Some(3 - i)));
+        }
+        assert_eq!(it.next(), None);
+
+        let mut it =
+            crate::successors_bounded(Some(1), 10, |i| if i < 2 { Some(i + 1) } else { None });
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.next(), Some(&2));
+        assert_eq!(it.size_hint(), (0, Some(8)));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.size_hint(), (0, Some(0)));
+
+        test(crate::successors_bounded(None, 4, |i: i32| Some(i)), &[]);
+        test(crate::successors_bounded(Some(1), 0, Some), &[]);
     }
 
     #[test]
-    fn filter_map_deref() {
-        let items = [0u8, 1, 1, 2, 4];
-        let it = convert(items).filter_map_deref(|&i| if i % 2 == 0 { Some(i) } else { None });
-        test_deref(it, &[0, 2, 4])
+    #[cfg(feature = "alloc")]
+    fn successors_bounded_to_vec() {
+        let it = crate::successors_bounded(Some('a'), 40, |c| {
+            if c < 'z' {
+                Some((c as u8 + 1) as char)
+            } else {
+                None
+            }
+        });
+        assert_eq!(it.size_hint(), (1, Some(40)));
+        let v = it.to_vec();
+        assert_eq!(v.len(), 26);
     }
 
     #[test]
-    fn find() {
-        let items = [0, 1];
-        let mut it = convert(items);
-        assert_eq!(it.clone().find(|&x| x % 2 == 1), Some(&1));
-        assert_eq!(it.find(|&x| x % 3 == 2), None);
+    #[cfg(feature = "alloc")]
+    fn chunks() {
+        let mut it = convert([1, 2, 3, 4, 5]).chunks(2);
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        let mut lens = Vec::new();
+        while let Some(chunk) = it.next() {
+            lens.push(chunk.len());
+        }
+        assert_eq!(lens, [2, 2, 1]);
+        assert_eq!(it.size_hint(), (0, Some(0)));
+
+        let mut it = convert([1, 2, 3, 4]).chunks(2);
+        assert_eq!(it.next(), Some(&[1, 2][..]));
+        it.get_mut().unwrap()[0] = 10;
+        assert_eq!(it.get(), Some(&[10, 2][..]));
+        assert_eq!(it.size_hint(), (1, Some(1)));
+        assert_eq!(it.next(), Some(&[3, 4][..]));
+        assert_eq!(it.next(), None);
+
+        assert_eq!(empty::<u8>().chunks(3).next(), None);
+        assert_eq!(convert_ref(["ab", "c"]).chunks(3).count(), 1);
     }
 
     #[test]
-    #[cfg(feature = "alloc")]
-    fn owned() {
-        let items = [0, 1];
-        let it = convert(items).owned();
-        assert_eq!(it.collect::<Vec<_>>(), items);
+    fn pairwise() {
+        let it = convert([1, 4, 9, 16]).pairwise(|prev, cur| cur - prev);
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        test(it, &[3, 5, 7]);
+
+        test(convert([1]).pairwise(|prev, cur| cur - prev), &[]);
+        test(empty::<i32>().pairwise(|prev, cur| cur - prev), &[]);
+
+        let mut it = convert(["a", "b", "c"]).pairwise(|&prev, &cur| (prev, cur));
+        assert_eq!(it.next(), Some(&("a", "b")));
+        assert_eq!(it.size_hint(), (1, Some(1)));
+        assert_eq!(it.next(), Some(&("b", "c")));
+        assert_eq!(it.next(), None);
     }
 
     #[test]
-    #[cfg(feature = "alloc")]
-    fn owned_str() {
-        let s = "The quick brown fox jumps over the lazy dog";
-        let words = s.split_whitespace().map(str::to_owned).collect::<Vec<_>>();
-        let it = convert_ref(s.split_whitespace()).owned();
-        assert_eq!(it.collect::<Vec<_>>(), words);
+    fn merge() {
+        let it = convert([1, 3, 5]).merge(convert([2, 4, 6]));
+        assert_eq!(it.size_hint(), (6, Some(6)));
+        test(it, &[1, 2, 3, 4, 5, 6]);
+
+        test(convert([1, 2, 7, 8]).merge(convert([3])), &[1, 2, 3, 7, 8]);
+        test(convert([4]).merge(convert([1, 2, 3])), &[1, 2, 3, 4]);
+        test(empty().merge(convert([1, 2])), &[1, 2]);
+        test(convert([1, 2]).merge(empty()), &[1, 2]);
+        test(empty::<i32>().merge(empty()), &[]);
+
+        let mut it = convert([1, 3, 5]).merge(convert([2, 4]));
+        it.advance();
+        assert_eq!(it.size_hint(), (4, Some(4)));
+        it.advance();
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        assert_eq!(it.fold(0, |n, _| n + 1), 3);
     }
 
     #[test]
-    fn position() {
-        let items = [0, 1];
-        let mut it = convert(items);
-        assert_eq!(it.clone().position(|&x| x % 2 == 1), Some(1));
-        assert_eq!(it.position(|&x| x % 3 == 2), None);
+    fn merge_by() {
+        let it = convert([(1, 'a'), (2, 'a')])
+            .merge_by(convert([(1, 'b'), (3, 'b')]), |x, y| x.0 <= y.0);
+        test(it, &[(1, 'a'), (1, 'b'), (2, 'a'), (3, 'b')]);
+
+        let it = convert([5, 3, 1]).merge_by(convert([4, 2]), |x, y| x > y);
+        test(it, &[5, 4, 3, 2, 1]);
     }
 
     #[test]
-    fn skip() {
-        let items = [0, 1, 2, 3];
-        let it = convert(items);
-        test(it.clone().skip(0), &[0, 1, 2, 3]);
-        test(it.clone().skip(2), &[2, 3]);
-        test(it.skip(5), &[]);
+    fn interleave() {
+        let it = convert([1, 2, 3]).interleave(convert([10, 20]));
+        assert_eq!(it.size_hint(), (5, Some(5)));
+        test(it, &[1, 10, 2, 20, 3]);
+
+        test(
+            convert([1]).interleave(convert([10, 20, 30])),
+            &[1, 10, 20, 30],
+        );
+        test(empty().interleave(convert([10, 20])), &[10, 20]);
+        test(convert([1, 2]).interleave(empty()), &[1, 2]);
+        test(empty::<i32>().interleave(empty()), &[]);
+
+        let mut it = convert([1, 2, 3]).interleave(convert([10]));
+        it.advance();
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        it.advance();
+        it.advance();
+        assert_eq!(it.size_hint(), (1, Some(1)));
     }
 
     #[test]
-    fn skip_while() {
-        let items = [0, 1, 2, 3];
-        let it = convert(items);
-        test(it.clone().skip_while(|&i| i < 0), &[0, 1, 2, 3]);
-        test(it.clone().skip_while(|&i| i < 2), &[2, 3]);
-        test(it.skip_while(|&i| i < 5), &[]);
+    fn zip_longest_map() {
+        let it = convert([1, 2, 3]).zip_longest_map(convert([10]), |a, b| (a.copied(), b.copied()));
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        test(it, &[(Some(1), Some(10)), (Some(2), None), (Some(3), None)]);
+
+        let it = convert([1]).zip_longest_map(convert([10, 20]), |a, b| (a.copied(), b.copied()));
+        test(it, &[(Some(1), Some(10)), (None, Some(20))]);
+
+        let it = empty::<i32>().zip_longest_map(empty::<i32>(), |_, _| 0);
+        test(it, &[]);
     }
 
     #[test]
-    fn take() {
-        let items = [0, 1, 2, 3];
-        let it = convert(items);
-        test(it.clone().take(0), &[]);
-        test(it.clone().take(2), &[0, 1]);
-        test(it.take(5), &[0, 1, 2, 3]);
+    fn get_at_convert_ref() {
+        let items = [1, 2, 3, 4];
+        let mut it = convert_ref(&items);
+        it.advance();
+        for k in 0..4 {
+            let mut stepped = it.clone();
+            for _ in 0..=k {
+                stepped.advance();
+            }
+            assert_eq!(it.get_at(k), stepped.get());
+            assert_eq!(it.clone().nth(k), stepped.get());
+        }
+        assert_eq!(it.nth(1), Some(&3));
+        assert_eq!(it.get(), Some(&3));
+        assert_eq!(it.get_at(0), Some(&4));
     }
 
     #[test]
-    fn take_while() {
-        let items = [0, 1, 2, 3];
-        let it = convert(items);
-        test(it.clone().take_while(|&i| i < 0), &[]);
-        test(it.clone().take_while(|&i| i < 2), &[0, 1]);
-        test(it.take_while(|&i| i < 5), &[0, 1, 2, 3]);
+    fn flat_map_double_ended() {
+        let items = [[0, 1], [2, 3]];
+        let it = convert(items).flat_map(|&i| convert(i));
+        test_back(it, &[3, 2, 1, 0]);
+
+        let mut it = convert(items).flat_map(|&i| convert(i));
+        assert_eq!(it.next(), Some(&0));
+        assert_eq!(it.next_back(), Some(&3));
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.next_back(), Some(&2));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+
+        // The two ends meet inside a single sub-iterator.
+        let mut it = convert(items).flat_map(|&i| convert(i));
+        assert_eq!(it.next_back(), Some(&3));
+        assert_eq!(it.next_back(), Some(&2));
+        assert_eq!(it.next(), Some(&0));
+        assert_eq!(it.next_back(), Some(&1));
+        assert_eq!(it.next(), None);
+
+        let mut it = convert(items).flat_map(|&i| convert(i));
+        assert_eq!(it.next_back(), Some(&3));
+        assert_eq!(it.next(), Some(&0));
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.next(), Some(&2));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+
+        let mut it = convert([[0, 1], [2, 3], [4, 5]]).flat_map(|&i| convert(i));
+        it.advance();
+        it.advance_back();
+        assert_eq!(it.fold(0, |acc, &i| acc * 10 + i), 1234);
+        let mut it = convert([[0, 1], [2, 3], [4, 5]]).flat_map(|&i| convert(i));
+        it.advance();
+        it.advance_back();
+        assert_eq!(it.rfold(0, |acc, &i| acc * 10 + i), 4321);
+
+        let mut it = convert([[0, 1], [2, 3]]).flat_map(|&i| convert(i));
+        it.advance_back();
+        *it.get_mut().unwrap() += 10;
+        assert_eq!(it.get(), Some(&13));
+        assert_eq!(it.rfold_mut(0, |acc, i| acc * 10 + *i), 210);
     }
 
-    fn _is_object_safe(_: &dyn StreamingIterator<Item = ()>) {}
+    #[test]
+    fn map_ref_mut() {
+        #[derive(Debug, PartialEq)]
+        struct Record {
+            name: &'static str,
+            value: i32,
+        }
 
-    fn _is_object_safe_mut(_: &dyn StreamingIteratorMut<Item = ()>) {}
+        let mut records = [
+            Record {
+                name: "a",
+                value: 1,
+            },
+            Record {
+                name: "b",
+                value: 2,
+            },
+        ];
+        let mut it = convert_mut(&mut records).map_ref_mut(|r| &r.value, |r| &mut r.value);
+        assert_eq!(it.next_mut(), Some(&mut 1));
+        *it.get_mut().unwrap() += 10;
+        assert_eq!(it.get(), Some(&11));
+        assert_eq!(it.next(), Some(&2));
+        *it.get_mut().unwrap() = 20;
+        assert_eq!(it.next(), None);
+        assert_eq!(
+            records[0],
+            Record {
+                name: "a",
+                value: 11
+            }
+        );
+        assert_eq!(
+            records[1],
+            Record {
+                name: "b",
+                value: 20
+            }
+        );
+
+        convert_mut(&mut records)
+            .map_ref_mut(|r| &r.name, |r| &mut r.name)
+            .fold_mut((), |(), name| *name = "c");
+        assert!(records.iter().all(|r| r.name == "c"));
+    }
 
     #[test]
-    fn empty_iterator() {
-        let mut it: Empty<u8> = empty();
+    fn by_ref_mut() {
+        let mut items = [0, 1, 2, 3, 4];
+        let mut it = convert_mut(&mut items);
+        it.by_ref_mut().take(2).for_each_mut(|i| *i += 10);
+        it.by_ref_mut()
+            .filter(|&i| i % 2 == 0)
+            .map_deref_mut(|i| *i = -*i)
+            .for_each(drop);
+        assert_eq!(it.next(), None);
+        assert_eq!(items, [10, 11, -2, 3, -4]);
+    }
 
+    #[test]
+    fn peekable_next_if() {
+        let mut it = convert([1, 2, 3, 10, 4]).peekable();
+        let mut small = 0;
+        while let Some(&i) = it.next_if(|&i| i < 5) {
+            small += i;
+        }
+        assert_eq!(small, 6);
+        assert_eq!(it.get(), None);
+        assert!(it.is_done());
+        assert_eq!(it.peek(), Some(&10));
+        assert_eq!(it.next_if(|&i| i == 10), Some(&10));
+        assert_eq!(it.get(), Some(&10));
+        assert!(!it.is_done());
+        assert_eq!(it.next(), Some(&4));
+        assert_eq!(it.next_if(|_| true), None);
         assert_eq!(it.next(), None);
     }
 
     #[test]
-    fn is_done_empty() {
-        let mut empty = empty::<u8>();
-        empty.advance();
-        assert!(empty.is_done());
+    fn flatten_size_hint() {
+        let items = [[].as_ref(), [1].as_ref(), [2, 3].as_ref()];
+        let hints = [(0, None), (0, None), (1, Some(1)), (0, Some(0))];
+        for (steps, &hint) in hints.iter().enumerate() {
+            let mut subs = items.map(convert_ref);
+            let mut it = convert_mut(&mut subs).flatten();
+            for _ in 0..steps {
+                it.advance();
+            }
+            assert_eq!(it.size_hint(), hint);
+            let len = 3 - steps;
+            assert!(hint.1.map_or(true, |upper| upper >= len));
+
+            let mut subs = items.map(convert_ref);
+            let mut it = convert_mut(&mut subs).flatten();
+            for _ in 0..steps {
+                it.advance();
+            }
+            assert_eq!(it.count(), len);
+        }
     }
 
     #[test]
-    fn is_done_map() {
-        let items = [1];
-        let mut it = convert(items).map_ref::<u16, _>(|_| panic!("only called during get()"));
+    fn flat_map_size_hint() {
+        let items = [[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+        let mut it = convert(items).flat_map(|&i| convert(i));
+        assert_eq!(it.size_hint(), (0, None));
+        assert_eq!(it.clone().count(), 9);
         it.advance();
-        assert!(!it.is_done());
+        assert_eq!(it.size_hint(), (2, None));
+        it.advance_back();
+        assert_eq!(it.size_hint(), (4, None));
+        assert_eq!(it.clone().count(), 7);
+        it.advance_back();
+        it.advance_back();
+        it.advance_back();
+        assert_eq!(it.size_hint(), (4, Some(4)));
+        assert_eq!(it.clone().count(), 4);
+        assert_eq!(it.clone().fold(0, |n, _| n + 1), 4);
+    }
+
+    #[test]
+    fn flatten_fold_includes_active_sub_iterator() {
+        let items = [[1, 2, 3].as_ref(), [4, 5].as_ref()];
+
+        let mut subs = items.map(convert_ref);
+        let mut it = convert_mut(&mut subs).flatten();
         it.advance();
-        assert!(it.is_done());
+        assert_eq!(it.fold(0, |acc, &i| acc * 10 + i), 2345);
+
+        let mut subs = items.map(convert_ref);
+        let mut it = convert_mut(&mut subs).flatten();
+        it.advance();
+        it.advance();
+        it.advance();
+        it.advance();
+        assert_eq!(it.fold(0, |acc, &i| acc * 10 + i), 5);
     }
 
     #[test]
-    fn rev() {
-        let items = [0, 1, 2, 3];
-        let it = convert(items);
-        test(it.rev(), &[3, 2, 1, 0]);
+    fn flatten_fold_mut_includes_active_sub_iterator() {
+        let mut a = [1, 2, 3];
+        let mut b = [4, 5];
+        let mut subs = [convert_mut(&mut a[..]), convert_mut(&mut b[..])];
+        let mut it = convert_mut(&mut subs).flatten();
+        it.advance();
+        assert_eq!(
+            it.fold_mut(0, |acc, i| {
+                *i *= 10;
+                acc + 1
+            }),
+            4
+        );
+        assert_eq!(a, [1, 20, 30]);
+        assert_eq!(b, [40, 50]);
     }
 
     #[test]
-    fn fold() {
-        let items = [0, 1, 2, 3];
-        let it = convert(items);
-        assert_eq!(it.fold(0, |acc, i| acc * 10 + i), 123);
+    #[cfg(feature = "alloc")]
+    fn rev_buffered() {
+        let mut n = 0;
+        let forward = from_fn(|| {
+            n += 1;
+            if n <= 4 {
+                Some(n)
+            } else {
+                None
+            }
+        });
+        let mut it = forward.rev_buffered();
+        assert_eq!(it.size_hint(), (0, None));
+        assert_eq!(it.next(), Some(&4));
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        assert_eq!(it.next_back(), Some(&1));
+        *it.get_mut().unwrap() = 10;
+        assert_eq!(it.get(), Some(&10));
+        assert_eq!(it.next(), Some(&3));
+        assert_eq!(it.next(), Some(&2));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+
+        test(convert([1, 2, 3]).rev_buffered(), &[3, 2, 1]);
+        test_back(convert([1, 2, 3]).rev_buffered(), &[1, 2, 3]);
+        test(empty::<i32>().rev_buffered(), &[]);
+
+        let words = ["a", "b"];
+        let it = convert_ref(words.iter().copied()).rev_buffered();
+        assert_eq!(it.fold(String::new(), |acc, s| acc + s), "ba");
     }
 
     #[test]
-    fn for_each() {
-        let items = [0, 1, 2, 3];
-        let it = convert(items);
-        let mut acc = 0;
-        it.for_each(|i| acc = acc * 10 + i);
-        assert_eq!(acc, 123);
+    fn changes_by() {
+        let readings = [5, 6, 4, 5, 9, 8, 10, 12, 3, 3];
+        let it = convert(readings).changes_by(2, |&a: &i32, &b: &i32| (a - b).abs());
+        assert_eq!(it.size_hint(), (1, Some(10)));
+        test(it, &[5, 9, 12, 3]);
+
+        let it = convert(readings).changes_by(0, |&a: &i32, &b: &i32| (a - b).abs());
+        test(it, &[5, 6, 4, 5, 9, 8, 10, 12, 3]);
+
+        test(
+            empty::<i32>().changes_by(0, |&a: &i32, &b: &i32| a - b),
+            &[],
+        );
     }
 
     #[test]
-    fn rfold() {
-        let items = [0, 1, 2, 3];
-        let it = convert(items);
-        assert_eq!(it.rfold(0, |acc, i| acc * 10 + i), 3210);
+    fn try_for_each_mut() {
+        let mut items = [1, 2, -1, 3];
+        let mut it = convert_mut(&mut items);
+        let double = |i: &mut i32| {
+            if *i < 0 {
+                Err(*i)
+            } else {
+                *i *= 2;
+                Ok(())
+            }
+        };
+        assert_eq!(it.try_for_each_mut(double), Err(-1));
+        assert_eq!(it.get(), Some(&-1));
+        *it.get_mut().unwrap() = 0;
+        assert_eq!(it.try_for_each_mut(double), Ok(()));
+        assert_eq!(it.get(), None);
+        assert_eq!(items, [2, 4, 0, 6]);
     }
 
     #[test]
-    fn for_each_rev() {
-        let items = [0, 1, 2, 3];
-        let it = convert(items);
-        let mut acc = 0;
-        it.rev().for_each(|i| acc = acc * 10 + i);
-        assert_eq!(acc, 3210);
+    fn nth_convert_ref_mut_skips_inner() {
+        use core::cell::Cell;
+
+        // Counts the calls made to the wrapped iterator.
+        struct Counted<'a, I> {
+            it: I,
+            calls: &'a Cell<usize>,
+        }
+
+        impl<I: Iterator> Iterator for Counted<'_, I> {
+            type Item = I::Item;
+
+            fn next(&mut self) -> Option<I::Item> {
+                self.calls.set(self.calls.get() + 1);
+                self.it.next()
+            }
+
+            fn nth(&mut self, n: usize) -> Option<I::Item> {
+                self.calls.set(self.calls.get() + 1);
+                self.it.nth(n)
+            }
+        }
+
+        impl<I: DoubleEndedIterator> DoubleEndedIterator for Counted<'_, I> {
+            fn next_back(&mut self) -> Option<I::Item> {
+                self.calls.set(self.calls.get() + 1);
+                self.it.next_back()
+            }
+
+            fn nth_back(&mut self, n: usize) -> Option<I::Item> {
+                self.calls.set(self.calls.get() + 1);
+                self.it.nth_back(n)
+            }
+        }
+
+        let mut items = [0; 100];
+        for (i, item) in items.iter_mut().enumerate() {
+            *item = i;
+        }
+
+        let calls = Cell::new(0);
+        let mut it = convert_ref(Counted {
+            it: items.iter(),
+            calls: &calls,
+        });
+        assert_eq!(it.nth(50), Some(&50));
+        assert_eq!(it.nth_back(40), Some(&59));
+        assert_eq!(it.get(), Some(&59));
+        assert_eq!(it.nth(10), None);
+        assert_eq!(calls.get(), 3);
+
+        let calls = Cell::new(0);
+        let mut it = convert_mut(Counted {
+            it: items.iter_mut(),
+            calls: &calls,
+        });
+        assert_eq!(it.nth(50), Some(&50));
+        *it.get_mut().unwrap() = 500;
+        assert_eq!(it.nth_back(40), Some(&59));
+        assert_eq!(it.nth_back(7), Some(&51));
+        assert_eq!(it.next_back(), None);
+        assert_eq!(calls.get(), 4);
+        assert_eq!(items[50], 500);
+
+        let calls = Cell::new(0);
+        let mut it = convert(Counted {
+            it: 0..100,
+            calls: &calls,
+        });
+        assert_eq!(it.nth_back(10), Some(&89));
+        assert_eq!(calls.get(), 1);
+
+        let mut it = convert([0, 1, 2, 3]).map(|&i| i * 10);
+        assert_eq!(it.nth_back(1), Some(&20));
+        assert_eq!(it.nth_back(0), Some(&10));
+        assert_eq!(it.nth_back(1), None);
     }
 
     #[test]
-    fn for_each_mut() {
-        let mut items = [0, 1, 2, 3];
+    fn advance_by_overstated_size_hint() {
+        // Claims far more elements than it yields.
+        struct Liar<I>(I);
 
-        convert_mut(&mut items).for_each_mut(|i: &mut i32| *i += 10);
-        assert_eq!(items, [10, 11, 12, 13]);
+        impl<I: Iterator> Iterator for Liar<I> {
+            type Item = I::Item;
 
-        // Note that filter sees a direct `&T` reference,
-        // rather than `&&mut T` like `Iterator` would.
-        convert_mut(&mut items)
-            .filter(|&i: &i32| i & 1 == 0)
-            .for_each_mut(|i: &mut i32| *i /= 2);
-        assert_eq!(items, [5, 11, 6, 13]);
+            fn next(&mut self) -> Option<I::Item> {
+                self.0.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (100, None)
+            }
+        }
+
+        impl<I: DoubleEndedIterator> DoubleEndedIterator for Liar<I> {
+            fn next_back(&mut self) -> Option<I::Item> {
+                self.0.next_back()
+            }
+        }
+
+        let mut it = convert(Liar([0, 1, 2].iter()));
+        assert!(it.advance_by(5).is_err());
+        assert!(it.is_done());
+
+        let mut it = convert(Liar([0, 1, 2].iter()));
+        assert!(it.advance_back_by(5).is_err());
+        assert!(it.is_done());
     }
 
     #[test]
-    fn into_streaming_iter() {
-        let items = [0, 1, 2, 3];
-        let iter = items.into_streaming_iter();
-        test(iter, &items);
-        let iter = (&items).into_streaming_iter_ref();
-        test(iter, &items);
-        let mut mut_items = items;
-        let iter = (&mut mut_items).into_streaming_iter_mut();
-        test(iter, &items);
+    fn fold_while_dyn() {
+        let mut items = convert([3, 4, 5, 6]);
+        let mut it: &mut dyn StreamingIterator<Item = i32> = &mut items;
+        let sum = (&mut it).fold_while(0, |sum, &i| {
+            if sum + i > 10 {
+                FoldWhile::Done(sum)
+            } else {
+                FoldWhile::Continue(sum + i)
+            }
+        });
+        assert_eq!(sum, 7);
+        assert_eq!(it.get(), Some(&5));
+    }
+
+    #[test]
+    fn dedup_size_hint_after_advance() {
+        let mut it = convert([1, 1]).dedup();
+        assert_eq!(it.size_hint(), (1, Some(2)));
+        it.advance();
+        assert_eq!(it.size_hint(), (0, Some(1)));
+
+        let mut it = convert([1, 1]).dedup_by(|a, b| a == b);
+        it.advance();
+        assert_eq!(it.size_hint(), (0, Some(1)));
+
+        let mut it = convert([1, 1]).dedup_by_key(|&i| i);
+        it.advance();
+        assert_eq!(it.size_hint(), (0, Some(1)));
+
+        let mut it = convert([1, 1]).dedup();
+        it.advance_back();
+        assert_eq!(it.size_hint(), (0, Some(1)));
     }
 }
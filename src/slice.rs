@@ -1,11 +1,210 @@
 use crate::{
-    DoubleEndedStreamingIterator, DoubleEndedStreamingIteratorMut, StreamingIterator,
-    StreamingIteratorMut,
+    AsRefIterator, DoubleEndedStreamingIterator, DoubleEndedStreamingIteratorMut,
+    ExactSizeStreamingIterator, IndexedStreamingIterator, StreamingIterator, StreamingIteratorMut,
 };
 
+use core::cmp;
+use core::iter;
 use core::mem;
 use core::num::NonZeroUsize;
 
+/// Creates an iterator over the elements of a mutable row-major `slice` with `cols` columns in
+/// column-major order, i.e. over the elements of its transpose.
+///
+/// If the length of the slice is not a multiple of `cols`, the trailing incomplete row is
+/// ignored. The slice itself is not reordered.
+///
+/// # Panics
+///
+/// Panics if `cols` is 0.
+pub fn transpose_slice_mut<T>(slice: &mut [T], cols: usize) -> TransposeSliceMut<'_, T> {
+    assert!(cols != 0, "cols is zero");
+    let rows = slice.len() / cols;
+    TransposeSliceMut {
+        slice,
+        rows,
+        cols,
+        front: 0,
+        back: rows * cols,
+        current: None,
+    }
+}
+
+/// A streaming iterator which returns the elements of a mutable row-major slice in column-major
+/// order.
+///
+/// This struct is created by the [`transpose_slice_mut`] function.
+pub struct TransposeSliceMut<'a, T> {
+    slice: &'a mut [T],
+    rows: usize,
+    cols: usize,
+    front: usize,
+    back: usize,
+    current: Option<usize>,
+}
+
+// Maps the `k`th position in column-major order to an index into a row-major matrix.
+pub(crate) fn column_major_index(k: usize, rows: usize, cols: usize) -> usize {
+    (k % rows) * cols + k / rows
+}
+
+impl<T> TransposeSliceMut<'_, T> {
+    fn index(&self, k: usize) -> usize {
+        column_major_index(k, self.rows, self.cols)
+    }
+}
+
+impl<T> StreamingIterator for TransposeSliceMut<'_, T> {
+    type Item = T;
+
+    fn advance(&mut self) {
+        if self.front < self.back {
+            self.current = Some(self.front);
+            self.front += 1;
+        } else {
+            self.current = None;
+        }
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.map(|k| &self.slice[self.index(k)])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn is_done(&self) -> bool {
+        self.current.is_none()
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ExactSizeStreamingIterator for TransposeSliceMut<'_, T> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+impl<T> StreamingIteratorMut for TransposeSliceMut<'_, T> {
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        let i = self.index(self.current?);
+        Some(&mut self.slice[i])
+    }
+}
+
+impl<T> DoubleEndedStreamingIterator for TransposeSliceMut<'_, T> {
+    fn advance_back(&mut self) {
+        if self.front < self.back {
+            self.back -= 1;
+            self.current = Some(self.back);
+        } else {
+            self.current = None;
+        }
+    }
+}
+
+impl<T> DoubleEndedStreamingIteratorMut for TransposeSliceMut<'_, T> {}
+
+/// Creates an iterator over all contiguous windows of length `size` in a `slice`.
+///
+/// The windows overlap. If the `slice` is shorter than `size`, the iterator returns no values.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+pub fn windows<T>(slice: &[T], size: usize) -> Windows<'_, T> {
+    let size = NonZeroUsize::new(size).expect("size is zero");
+    Windows {
+        slice,
+        size,
+        front: 0,
+        back: (slice.len() + 1).saturating_sub(size.get()),
+        current: None,
+    }
+}
+
+/// A streaming iterator which returns overlapping subslices of length `size`.
+///
+/// This struct is created by the [`windows`] function.
+#[derive(Clone, Debug)]
+pub struct Windows<'a, T> {
+    slice: &'a [T],
+    size: NonZeroUsize,
+    front: usize,
+    back: usize,
+    current: Option<usize>,
+}
+
+impl<T> Windows<'_, T> {
+    /// Returns the index of the current window, which is the offset of its first element in the
+    /// original slice, or `None` if there is no current window.
+    pub fn index(&self) -> Option<usize> {
+        self.current
+    }
+}
+
+impl<T> StreamingIterator for Windows<'_, T> {
+    type Item = [T];
+
+    fn advance(&mut self) {
+        if self.front < self.back {
+            self.current = Some(self.front);
+            self.front += 1;
+        } else {
+            self.current = None;
+        }
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        let start = self.current?;
+        Some(&self.slice[start..start + self.size.get()])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.back - self.front
+    }
+
+    fn nth(&mut self, n: usize) -> Option<&Self::Item> {
+        self.front = cmp::min(self.front.saturating_add(n), self.back);
+        self.advance();
+        (*self).get()
+    }
+}
+
+impl<T> ExactSizeStreamingIterator for Windows<'_, T> {}
+
+impl<T> IndexedStreamingIterator for Windows<'_, T> {
+    fn get_at(&self, index: usize) -> Option<&Self::Item> {
+        let start = self.front.checked_add(index)?;
+        if start < self.back {
+            Some(&self.slice[start..start + self.size.get()])
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> DoubleEndedStreamingIterator for Windows<'_, T> {
+    fn advance_back(&mut self) {
+        if self.front < self.back {
+            self.back -= 1;
+            self.current = Some(self.back);
+        } else {
+            self.current = None;
+        }
+    }
+}
+
 /// Creates an iterator over all contiguous windows of length `size` in a mutable `slice`.
 ///
 /// The windows overlap and may be mutated via `StreamingIteratorMut`.
@@ -19,9 +218,40 @@ pub fn windows_mut<T>(slice: &mut [T], size: usize) -> WindowsMut<'_, T> {
         slice,
         size: NonZeroUsize::new(size).expect("size is zero"),
         position: Position::Init,
+        offset: 0,
     }
 }
 
+/// Calls a closure on each contiguous window of length `size` in a mutable `slice`, from front to
+/// back.
+///
+/// This is equivalent to `windows_mut(slice, size).for_each_mut(f)`.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+pub fn for_each_window_mut<T, F>(slice: &mut [T], size: usize, f: F)
+where
+    F: FnMut(&mut [T]),
+{
+    windows_mut(slice, size).for_each_mut(f)
+}
+
+/// Calls a closure on each contiguous window of length `size` in a mutable `slice`, from back to
+/// front.
+///
+/// This is equivalent to `windows_mut(slice, size).rev().for_each_mut(f)`.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+pub fn for_each_window_mut_rev<T, F>(slice: &mut [T], size: usize, f: F)
+where
+    F: FnMut(&mut [T]),
+{
+    windows_mut(slice, size).rev().for_each_mut(f)
+}
+
 /// A streaming iterator which returns overlapping mutable subslices of length `size`.
 ///
 /// This struct is created by the [`windows_mut`] function.
@@ -29,6 +259,7 @@ pub struct WindowsMut<'a, T> {
     slice: &'a mut [T],
     size: NonZeroUsize,
     position: Position,
+    offset: usize,
 }
 
 enum Position {
@@ -38,6 +269,34 @@ enum Position {
 }
 
 impl<T> WindowsMut<'_, T> {
+    /// Returns the index of the current window, which is the offset of its first element in the
+    /// original slice, or `None` if there is no current window.
+    ///
+    /// The index is counted from the front of the slice, whether the window was reached by
+    /// advancing from the front or from the back.
+    ///
+    /// ```
+    /// # use streaming_iterator::{windows_mut, DoubleEndedStreamingIterator, StreamingIterator};
+    /// let mut items = [0; 5];
+    /// let mut it = windows_mut(&mut items, 2);
+    /// it.advance();
+    /// assert_eq!(it.index(), Some(0));
+    /// it.advance_back();
+    /// assert_eq!(it.index(), Some(3));
+    /// ```
+    pub fn index(&self) -> Option<usize> {
+        // Not called `position`, since that name is taken by `StreamingIterator::position`.
+        match self.position {
+            Position::Init => None,
+            Position::Front if self.slice.len() >= self.size.get() => Some(self.offset),
+            Position::Back => {
+                let start = self.slice.len().checked_sub(self.size.get())?;
+                Some(self.offset + start)
+            }
+            Position::Front => None,
+        }
+    }
+
     fn consume(&mut self) {
         match self.position {
             Position::Init => {}
@@ -45,6 +304,7 @@ impl<T> WindowsMut<'_, T> {
                 let slice = mem::take(&mut self.slice);
                 if let Some((_, tail)) = slice.split_first_mut() {
                     self.slice = tail;
+                    self.offset += 1;
                 }
             }
             Position::Back => {
@@ -74,12 +334,14 @@ impl<T> WindowsMut<'_, T> {
         self.slice.get_mut(start..)
     }
 
-    fn len(&self) -> usize {
-        let len = match self.position {
-            Position::Init => self.slice.len(),
-            _ => self.slice.len().saturating_sub(1),
-        };
-        len.saturating_sub(self.size.get() - 1)
+    // The part of `slice` which has not been consumed yet, excluding the current window's element
+    // which will be dropped by the next call to `consume`.
+    fn remaining(&self) -> &[T] {
+        match self.position {
+            Position::Init => self.slice,
+            Position::Front => self.slice.get(1..).unwrap_or(&[]),
+            Position::Back => self.slice.split_last().map_or(&[], |(_, head)| head),
+        }
     }
 }
 
@@ -116,6 +378,33 @@ impl<T> StreamingIterator for WindowsMut<'_, T> {
     fn count(self) -> usize {
         self.len()
     }
+
+    fn nth(&mut self, n: usize) -> Option<&Self::Item> {
+        self.consume();
+        let slice = mem::take(&mut self.slice);
+        let skip = cmp::min(n, slice.len());
+        self.slice = &mut slice[skip..];
+        self.offset += skip;
+        self.position = Position::Front;
+        self.get_front()
+    }
+}
+
+impl<T> IndexedStreamingIterator for WindowsMut<'_, T> {
+    fn get_at(&self, index: usize) -> Option<&Self::Item> {
+        let end = index.checked_add(self.size.get())?;
+        self.remaining().get(index..end)
+    }
+}
+
+impl<T> ExactSizeStreamingIterator for WindowsMut<'_, T> {
+    fn len(&self) -> usize {
+        let len = match self.position {
+            Position::Init => self.slice.len(),
+            _ => self.slice.len().saturating_sub(1),
+        };
+        len.saturating_sub(self.size.get() - 1)
+    }
 }
 
 impl<T> StreamingIteratorMut for WindowsMut<'_, T> {
@@ -152,52 +441,1570 @@ impl<T> DoubleEndedStreamingIteratorMut for WindowsMut<'_, T> {
     }
 }
 
-#[test]
-fn test_windows_mut() {
-    let slice: &mut [_] = &mut [0; 6];
+/// Creates an iterator over the windows of length `size` in a mutable `slice` which start every
+/// `step` elements.
+///
+/// The first window starts at the beginning of the slice, and each following one starts `step`
+/// elements after the previous one. Windows overlap if `step` is less than `size`, and elements
+/// are skipped if it is greater. Iteration stops at the last window that fits entirely in the
+/// slice.
+///
+/// ```
+/// # use streaming_iterator::{windows_mut_step, StreamingIteratorMut};
+/// let mut items = [0; 5];
+/// windows_mut_step(&mut items, 2, 3).for_each_mut(|w| w[0] = 1);
+/// assert_eq!(items, [1, 0, 0, 1, 0]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `size` or `step` is 0.
+pub fn windows_mut_step<T>(slice: &mut [T], size: usize, step: usize) -> WindowsMutStep<'_, T> {
+    WindowsMutStep {
+        slice,
+        size: NonZeroUsize::new(size).expect("size is zero"),
+        step: NonZeroUsize::new(step).expect("step is zero"),
+        started: false,
+        offset: 0,
+    }
+}
 
-    windows_mut(slice, 3).fold_mut(0, |i, win| {
-        win.copy_from_slice(&[i; 3]);
-        i + 1
-    });
-    assert_eq!(slice, &[0, 1, 2, 3, 3, 3]);
+/// A streaming iterator which returns mutable subslices of length `size` starting every `step`
+/// elements.
+///
+/// This struct is created by the [`windows_mut_step`] function.
+pub struct WindowsMutStep<'a, T> {
+    slice: &'a mut [T],
+    size: NonZeroUsize,
+    step: NonZeroUsize,
+    started: bool,
+    offset: usize,
+}
 
-    windows_mut(slice, 2).rfold_mut(0, |i, win| {
-        win.copy_from_slice(&[i; 2]);
-        i + 1
-    });
-    assert_eq!(slice, &[4, 4, 3, 2, 1, 0]);
+impl<T> WindowsMutStep<'_, T> {
+    /// Returns the index of the current window, which is the offset of its first element in the
+    /// original slice, or `None` if there is no current window.
+    pub fn index(&self) -> Option<usize> {
+        if self.started && !self.is_done() {
+            Some(self.offset)
+        } else {
+            None
+        }
+    }
 
-    let mut i = 0;
-    let mut iter = windows_mut(slice, 1);
-    while let Some(win) = iter.next_mut() {
-        win.copy_from_slice(&[i]);
-        i += 1;
+    fn windows(&self) -> usize {
+        match self.slice.len().checked_sub(self.size.get()) {
+            Some(extra) => extra / self.step.get() + 1,
+            None => 0,
+        }
+    }
+}
 
-        if let Some(win) = iter.next_back_mut() {
-            win.copy_from_slice(&[i]);
-            i += 1;
+impl<T> StreamingIterator for WindowsMutStep<'_, T> {
+    type Item = [T];
+
+    fn advance(&mut self) {
+        if self.started {
+            let slice = mem::take(&mut self.slice);
+            let step = cmp::min(self.step.get(), slice.len());
+            self.slice = &mut slice[step..];
+            self.offset += step;
         }
+        self.started = true;
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        if self.started {
+            self.slice.get(..self.size.get())
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn is_done(&self) -> bool {
+        self.slice.len() < self.size.get()
+    }
+
+    fn count(self) -> usize {
+        self.len()
     }
-    assert_eq!(slice, &[0, 2, 4, 5, 3, 1]);
 }
 
-#[test]
-fn test_windows_mut_count() {
-    let slice: &mut [_] = &mut [0; 6];
+impl<T> ExactSizeStreamingIterator for WindowsMutStep<'_, T> {
+    fn len(&self) -> usize {
+        let windows = self.windows();
+        if self.started {
+            windows.saturating_sub(1)
+        } else {
+            windows
+        }
+    }
+}
 
-    assert_eq!(windows_mut(slice, 3).count(), 4);
-    assert_eq!(windows_mut(slice, 6).count(), 1);
-    assert_eq!(windows_mut(slice, 9).count(), 0);
+impl<T> StreamingIteratorMut for WindowsMutStep<'_, T> {
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        if self.started {
+            self.slice.get_mut(..self.size.get())
+        } else {
+            None
+        }
+    }
+}
 
-    let mut iter = windows_mut(slice, 3);
-    assert_eq!(iter.size_hint(), (4, Some(4)));
-    iter.advance();
-    assert_eq!(iter.count(), 3);
+/// Creates an iterator over overlapping windows of `N` elements of a mutable `slice`.
+///
+/// This is equivalent to `windows_mut(slice, N)`, but each window is yielded as a `[T; N]` array,
+/// so that it can be indexed without bounds checks.
+///
+/// ```
+/// # use streaming_iterator::{array_windows_mut, StreamingIteratorMut};
+/// let mut items = [1, 2, 3, 4];
+/// array_windows_mut::<_, 2>(&mut items).for_each_mut(|[a, b]| *b += *a);
+/// assert_eq!(items, [1, 3, 6, 10]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `N` is 0.
+pub fn array_windows_mut<T, const N: usize>(slice: &mut [T]) -> ArrayWindowsMut<'_, T, N> {
+    assert!(N != 0, "window size must be non-zero");
+    ArrayWindowsMut {
+        windows: windows_mut(slice, N),
+    }
 }
 
-#[test]
-#[should_panic]
-fn test_windows_mut_0() {
-    let _: WindowsMut<'_, i32> = windows_mut(&mut [], 0);
+/// A streaming iterator which returns overlapping mutable windows of `N` elements as arrays.
+///
+/// This struct is created by the [`array_windows_mut`] function.
+pub struct ArrayWindowsMut<'a, T, const N: usize> {
+    windows: WindowsMut<'a, T>,
+}
+
+impl<T, const N: usize> StreamingIterator for ArrayWindowsMut<'_, T, N> {
+    type Item = [T; N];
+
+    fn advance(&mut self) {
+        self.windows.advance();
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.windows.get().and_then(|w| w.try_into().ok())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows.size_hint()
+    }
+
+    fn is_done(&self) -> bool {
+        self.windows.is_done()
+    }
+
+    fn count(self) -> usize {
+        self.windows.count()
+    }
+}
+
+impl<T, const N: usize> ExactSizeStreamingIterator for ArrayWindowsMut<'_, T, N> {
+    fn len(&self) -> usize {
+        self.windows.len()
+    }
+}
+
+impl<T, const N: usize> StreamingIteratorMut for ArrayWindowsMut<'_, T, N> {
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.windows.get_mut().and_then(|w| w.try_into().ok())
+    }
+}
+
+impl<T, const N: usize> DoubleEndedStreamingIterator for ArrayWindowsMut<'_, T, N> {
+    fn advance_back(&mut self) {
+        self.windows.advance_back();
+    }
+}
+
+impl<T, const N: usize> DoubleEndedStreamingIteratorMut for ArrayWindowsMut<'_, T, N> {}
+
+/// Creates an iterator over non-overlapping chunks of `N` elements of a `slice`, starting at the
+/// beginning of the slice.
+///
+/// Each chunk is yielded as a `[T; N]` array. If `N` does not divide the length of the slice,
+/// the last up to `N - 1` elements are omitted and can be retrieved from the
+/// [`remainder`](ArrayChunks::remainder) method of the iterator.
+///
+/// # Panics
+///
+/// Panics if `N` is 0.
+pub fn array_chunks<T, const N: usize>(slice: &[T]) -> ArrayChunks<'_, T, N> {
+    assert!(N != 0, "chunk size must be non-zero");
+    let len = slice.len() - slice.len() % N;
+    let (slice, rem) = slice.split_at(len);
+    ArrayChunks {
+        slice,
+        rem,
+        current: None,
+    }
+}
+
+/// A streaming iterator which returns non-overlapping chunks of `N` elements as arrays.
+///
+/// This struct is created by the [`array_chunks`] function.
+#[derive(Clone, Debug)]
+pub struct ArrayChunks<'a, T, const N: usize> {
+    slice: &'a [T],
+    rem: &'a [T],
+    current: Option<&'a [T; N]>,
+}
+
+impl<'a, T, const N: usize> ArrayChunks<'a, T, N> {
+    /// Returns the remainder of the original slice that is not going to be returned by the
+    /// iterator. The returned slice has at most `N - 1` elements.
+    pub fn remainder(&self) -> &'a [T] {
+        self.rem
+    }
+}
+
+impl<T, const N: usize> StreamingIterator for ArrayChunks<'_, T, N> {
+    type Item = [T; N];
+
+    fn advance(&mut self) {
+        if self.slice.is_empty() {
+            self.current = None;
+        } else {
+            let (head, tail) = self.slice.split_at(N);
+            self.current = head.try_into().ok();
+            self.slice = tail;
+        }
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len() / N;
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.slice.len() / N
+    }
+}
+
+impl<T, const N: usize> ExactSizeStreamingIterator for ArrayChunks<'_, T, N> {}
+
+impl<T, const N: usize> DoubleEndedStreamingIterator for ArrayChunks<'_, T, N> {
+    fn advance_back(&mut self) {
+        if self.slice.is_empty() {
+            self.current = None;
+        } else {
+            let (head, tail) = self.slice.split_at(self.slice.len() - N);
+            self.current = tail.try_into().ok();
+            self.slice = head;
+        }
+    }
+}
+
+/// Creates an iterator which endlessly repeats the elements of a `slice`.
+///
+/// Unlike `StreamingIterator::cycle`, this does not require cloning an iterator: reaching the
+/// end of the slice simply wraps back around to its start. Iterating from the back repeats the
+/// elements in reverse order. If the slice is empty, the iterator yields nothing.
+///
+/// ```
+/// # use streaming_iterator::{cycle_slice, StreamingIterator};
+/// let mut it = cycle_slice(&[1, 2]);
+/// assert_eq!(it.next(), Some(&1));
+/// assert_eq!(it.next(), Some(&2));
+/// assert_eq!(it.next(), Some(&1));
+/// ```
+pub fn cycle_slice<T>(slice: &[T]) -> CycleSlice<'_, T> {
+    CycleSlice {
+        slice,
+        front: 0,
+        back: 0,
+        current: None,
+    }
+}
+
+/// A streaming iterator which endlessly repeats the elements of a slice.
+///
+/// This struct is created by the [`cycle_slice`] function.
+#[derive(Clone, Debug)]
+pub struct CycleSlice<'a, T> {
+    slice: &'a [T],
+    front: usize,
+    back: usize,
+    current: Option<usize>,
+}
+
+impl<T> StreamingIterator for CycleSlice<'_, T> {
+    type Item = T;
+
+    fn advance(&mut self) {
+        if self.slice.is_empty() {
+            self.current = None;
+        } else {
+            self.current = Some(self.front);
+            self.front = (self.front + 1) % self.slice.len();
+        }
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.map(|i| &self.slice[i])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.slice.is_empty() {
+            (0, Some(0))
+        } else {
+            (usize::MAX, None)
+        }
+    }
+}
+
+impl<'a, T> AsRefIterator<'a> for CycleSlice<'a, T> {
+    type RefIter = iter::Chain<core::slice::Iter<'a, T>, iter::Cycle<core::slice::Iter<'a, T>>>;
+
+    fn ref_iter(self) -> Self::RefIter {
+        self.slice[self.front..]
+            .iter()
+            .chain(self.slice.iter().cycle())
+    }
+}
+
+impl<T> DoubleEndedStreamingIterator for CycleSlice<'_, T> {
+    fn advance_back(&mut self) {
+        if self.slice.is_empty() {
+            self.current = None;
+        } else {
+            self.back = self.back.checked_sub(1).unwrap_or(self.slice.len() - 1);
+            self.current = Some(self.back);
+        }
+    }
+}
+
+/// Creates an iterator over non-overlapping chunks of `size` elements of a mutable `slice`,
+/// starting at the beginning of the slice.
+///
+/// If `size` does not divide the length of the slice, the last chunk is shorter.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+pub fn chunks_mut<T>(slice: &mut [T], size: usize) -> ChunksMut<'_, T> {
+    ChunksMut {
+        slice,
+        size: NonZeroUsize::new(size).expect("size is zero"),
+        current: None,
+    }
+}
+
+/// A streaming iterator which returns non-overlapping mutable subslices of `size` elements.
+///
+/// This struct is created by the [`chunks_mut`] function.
+#[derive(Debug)]
+pub struct ChunksMut<'a, T> {
+    slice: &'a mut [T],
+    size: NonZeroUsize,
+    current: Option<&'a mut [T]>,
+}
+
+impl<T> StreamingIterator for ChunksMut<'_, T> {
+    type Item = [T];
+
+    fn advance(&mut self) {
+        let slice = mem::take(&mut self.slice);
+        self.current = if slice.is_empty() {
+            None
+        } else {
+            let (head, tail) = slice.split_at_mut(cmp::min(self.size.get(), slice.len()));
+            self.slice = tail;
+            Some(head)
+        };
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.as_deref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ExactSizeStreamingIterator for ChunksMut<'_, T> {
+    fn len(&self) -> usize {
+        let len = self.slice.len();
+        len / self.size.get() + (len % self.size.get() != 0) as usize
+    }
+}
+
+impl<T> StreamingIteratorMut for ChunksMut<'_, T> {
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.current.as_deref_mut()
+    }
+}
+
+impl<T> DoubleEndedStreamingIterator for ChunksMut<'_, T> {
+    fn advance_back(&mut self) {
+        let slice = mem::take(&mut self.slice);
+        self.current = if slice.is_empty() {
+            None
+        } else {
+            let rem = slice.len() % self.size.get();
+            let len = if rem == 0 { self.size.get() } else { rem };
+            let (head, tail) = slice.split_at_mut(slice.len() - len);
+            self.slice = head;
+            Some(tail)
+        };
+    }
+}
+
+impl<T> DoubleEndedStreamingIteratorMut for ChunksMut<'_, T> {}
+
+/// Creates an iterator over non-overlapping chunks of `size` elements of a mutable `slice`,
+/// starting at the end of the slice.
+///
+/// If `size` does not divide the length of the slice, the last chunk, which covers the start of
+/// the slice, is shorter.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+pub fn rchunks_mut<T>(slice: &mut [T], size: usize) -> RChunksMut<'_, T> {
+    RChunksMut {
+        slice,
+        size: NonZeroUsize::new(size).expect("size is zero"),
+        current: None,
+    }
+}
+
+/// A streaming iterator which returns non-overlapping mutable subslices of `size` elements,
+/// starting at the end of the slice.
+///
+/// This struct is created by the [`rchunks_mut`] function.
+#[derive(Debug)]
+pub struct RChunksMut<'a, T> {
+    slice: &'a mut [T],
+    size: NonZeroUsize,
+    current: Option<&'a mut [T]>,
+}
+
+impl<T> StreamingIterator for RChunksMut<'_, T> {
+    type Item = [T];
+
+    fn advance(&mut self) {
+        let slice = mem::take(&mut self.slice);
+        self.current = if slice.is_empty() {
+            None
+        } else {
+            let (head, tail) =
+                slice.split_at_mut(slice.len() - cmp::min(self.size.get(), slice.len()));
+            self.slice = head;
+            Some(tail)
+        };
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.as_deref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ExactSizeStreamingIterator for RChunksMut<'_, T> {
+    fn len(&self) -> usize {
+        let len = self.slice.len();
+        len / self.size.get() + (len % self.size.get() != 0) as usize
+    }
+}
+
+impl<T> StreamingIteratorMut for RChunksMut<'_, T> {
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.current.as_deref_mut()
+    }
+}
+
+impl<T> DoubleEndedStreamingIterator for RChunksMut<'_, T> {
+    fn advance_back(&mut self) {
+        let slice = mem::take(&mut self.slice);
+        self.current = if slice.is_empty() {
+            None
+        } else {
+            let rem = slice.len() % self.size.get();
+            let len = if rem == 0 { self.size.get() } else { rem };
+            let (head, tail) = slice.split_at_mut(len);
+            self.slice = tail;
+            Some(head)
+        };
+    }
+}
+
+impl<T> DoubleEndedStreamingIteratorMut for RChunksMut<'_, T> {}
+
+/// Creates an iterator over the runs of consecutive elements of a mutable `slice` which map to
+/// equal keys.
+///
+/// Each run is yielded as a mutable subslice, so that it can, for example, be reduced into its
+/// first element in place. Runs are maximal: two adjacent elements belong to the same run exactly
+/// when `key` returns equal values for them, so a run containing a single element is yielded on
+/// its own. `key` should be deterministic, as it may be called more than once per element.
+pub fn group_runs_mut<T, K, F>(slice: &mut [T], key: F) -> GroupRunsMut<'_, T, F>
+where
+    K: PartialEq,
+    F: FnMut(&T) -> K,
+{
+    GroupRunsMut {
+        slice,
+        run: None,
+        key,
+    }
+}
+
+/// A streaming iterator which returns mutable subslices of consecutive elements with equal keys.
+///
+/// This struct is created by the [`group_runs_mut`] function.
+pub struct GroupRunsMut<'a, T, F> {
+    slice: &'a mut [T],
+    run: Option<&'a mut [T]>,
+    key: F,
+}
+
+impl<T, K, F> StreamingIterator for GroupRunsMut<'_, T, F>
+where
+    K: PartialEq,
+    F: FnMut(&T) -> K,
+{
+    type Item = [T];
+
+    fn advance(&mut self) {
+        let slice = mem::take(&mut self.slice);
+        self.run = match slice.split_first() {
+            Some((first, rest)) => {
+                let first = (self.key)(first);
+                let len = 1 + rest.iter().take_while(|x| (self.key)(x) == first).count();
+                let (run, tail) = slice.split_at_mut(len);
+                self.slice = tail;
+                Some(run)
+            }
+            None => None,
+        };
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.run.as_deref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len();
+        (cmp::min(len, 1), Some(len))
+    }
+}
+
+impl<T, K, F> StreamingIteratorMut for GroupRunsMut<'_, T, F>
+where
+    K: PartialEq,
+    F: FnMut(&T) -> K,
+{
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.run.as_deref_mut()
+    }
+}
+
+impl<T, K, F> DoubleEndedStreamingIterator for GroupRunsMut<'_, T, F>
+where
+    K: PartialEq,
+    F: FnMut(&T) -> K,
+{
+    fn advance_back(&mut self) {
+        let slice = mem::take(&mut self.slice);
+        self.run = match slice.split_last() {
+            Some((last, rest)) => {
+                let last = (self.key)(last);
+                let len = 1 + rest
+                    .iter()
+                    .rev()
+                    .take_while(|x| (self.key)(x) == last)
+                    .count();
+                let (head, run) = slice.split_at_mut(slice.len() - len);
+                self.slice = head;
+                Some(run)
+            }
+            None => None,
+        };
+    }
+}
+
+impl<T, K, F> DoubleEndedStreamingIteratorMut for GroupRunsMut<'_, T, F>
+where
+    K: PartialEq,
+    F: FnMut(&T) -> K,
+{
+}
+
+/// Creates an iterator over the runs of consecutive elements of a mutable `slice` for which
+/// `same` returns `true` on each adjacent pair.
+///
+/// Each run is yielded as a mutable subslice. Unlike [`group_runs_mut`], elements are compared
+/// with their neighbours directly rather than through a key, like `<[T]>::chunk_by_mut`. An empty
+/// slice produces no runs.
+///
+/// ```
+/// # use streaming_iterator::{group_adjacent_mut, StreamingIteratorMut};
+/// let mut items = [1, 1, 2, 3, 3];
+/// group_adjacent_mut(&mut items, |a, b| a == b).for_each_mut(|run| run[1..].fill(0));
+/// assert_eq!(items, [1, 0, 2, 3, 0]);
+/// ```
+pub fn group_adjacent_mut<T, F>(slice: &mut [T], same: F) -> GroupAdjacentMut<'_, T, F>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    GroupAdjacentMut {
+        slice,
+        run: None,
+        same,
+    }
+}
+
+/// A streaming iterator which returns mutable subslices of consecutive elements grouped by a
+/// predicate on adjacent pairs.
+///
+/// This struct is created by the [`group_adjacent_mut`] function.
+pub struct GroupAdjacentMut<'a, T, F> {
+    slice: &'a mut [T],
+    run: Option<&'a mut [T]>,
+    same: F,
+}
+
+impl<T, F> StreamingIterator for GroupAdjacentMut<'_, T, F>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    type Item = [T];
+
+    fn advance(&mut self) {
+        let slice = mem::take(&mut self.slice);
+        self.run = if slice.is_empty() {
+            None
+        } else {
+            let len = 1 + slice
+                .windows(2)
+                .take_while(|w| (self.same)(&w[0], &w[1]))
+                .count();
+            let (run, tail) = slice.split_at_mut(len);
+            self.slice = tail;
+            Some(run)
+        };
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.run.as_deref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len();
+        (cmp::min(len, 1), Some(len))
+    }
+}
+
+impl<T, F> StreamingIteratorMut for GroupAdjacentMut<'_, T, F>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.run.as_deref_mut()
+    }
+}
+
+impl<T, F> DoubleEndedStreamingIterator for GroupAdjacentMut<'_, T, F>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    fn advance_back(&mut self) {
+        let slice = mem::take(&mut self.slice);
+        self.run = if slice.is_empty() {
+            None
+        } else {
+            let len = 1 + slice
+                .windows(2)
+                .rev()
+                .take_while(|w| (self.same)(&w[0], &w[1]))
+                .count();
+            let (head, run) = slice.split_at_mut(slice.len() - len);
+            self.slice = head;
+            Some(run)
+        };
+    }
+}
+
+impl<T, F> DoubleEndedStreamingIteratorMut for GroupAdjacentMut<'_, T, F> where
+    F: FnMut(&T, &T) -> bool
+{
+}
+
+/// Creates an iterator over the mutable subslices of `slice` separated by elements matching
+/// `is_sep`.
+///
+/// The separators themselves are not part of any subslice. As with `<[T]>::split_mut`, a
+/// separator at the start or end of the slice produces an empty subslice before or after it, as
+/// do two adjacent separators between them, and an empty slice produces a single empty subslice.
+///
+/// ```
+/// # use streaming_iterator::{split_mut, StreamingIteratorMut};
+/// let mut line = *b"ab,cd";
+/// let mut it = split_mut(&mut line, |&b| b == b',');
+/// while let Some(field) = it.next_mut() {
+///     field.make_ascii_uppercase();
+/// }
+/// assert_eq!(&line, b"AB,CD");
+/// ```
+pub fn split_mut<T, F>(slice: &mut [T], is_sep: F) -> SplitMut<'_, T, F>
+where
+    F: FnMut(&T) -> bool,
+{
+    SplitMut {
+        slice,
+        current: None,
+        is_sep,
+        finished: false,
+    }
+}
+
+/// A streaming iterator which returns mutable subslices separated by elements matching a
+/// predicate.
+///
+/// This struct is created by the [`split_mut`] function.
+pub struct SplitMut<'a, T, F> {
+    slice: &'a mut [T],
+    current: Option<&'a mut [T]>,
+    is_sep: F,
+    finished: bool,
+}
+
+impl<T, F> StreamingIterator for SplitMut<'_, T, F>
+where
+    F: FnMut(&T) -> bool,
+{
+    type Item = [T];
+
+    fn advance(&mut self) {
+        if self.finished {
+            self.current = None;
+            return;
+        }
+        let slice = mem::take(&mut self.slice);
+        self.current = match slice.iter().position(&mut self.is_sep) {
+            Some(i) => {
+                let (head, tail) = slice.split_at_mut(i);
+                self.slice = &mut tail[1..];
+                Some(head)
+            }
+            None => {
+                self.finished = true;
+                Some(slice)
+            }
+        };
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.as_deref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(self.slice.len() + 1))
+        }
+    }
+}
+
+impl<T, F> StreamingIteratorMut for SplitMut<'_, T, F>
+where
+    F: FnMut(&T) -> bool,
+{
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.current.as_deref_mut()
+    }
+}
+
+impl<T, F> DoubleEndedStreamingIterator for SplitMut<'_, T, F>
+where
+    F: FnMut(&T) -> bool,
+{
+    fn advance_back(&mut self) {
+        if self.finished {
+            self.current = None;
+            return;
+        }
+        let slice = mem::take(&mut self.slice);
+        self.current = match slice.iter().rposition(&mut self.is_sep) {
+            Some(i) => {
+                let (head, tail) = slice.split_at_mut(i);
+                self.slice = head;
+                Some(&mut tail[1..])
+            }
+            None => {
+                self.finished = true;
+                Some(slice)
+            }
+        };
+    }
+}
+
+impl<T, F> DoubleEndedStreamingIteratorMut for SplitMut<'_, T, F> where F: FnMut(&T) -> bool {}
+
+/// Creates an iterator over the elements of a mutable `slice` rotated left by `mid` places.
+///
+/// Elements are yielded starting from index `mid`, wrapping around to the first `mid` elements
+/// at the end. The rotation is virtual: the slice itself is left in its original order and each
+/// element is visited exactly once. Use `<[T]>::rotate_left` to move the elements in place
+/// instead.
+///
+/// # Panics
+///
+/// Panics if `mid` is greater than the length of the slice.
+pub fn rotate_left_mut<T>(slice: &mut [T], mid: usize) -> RotateLeftMut<'_, T> {
+    assert!(mid <= slice.len(), "mid is out of bounds");
+    RotateLeftMut {
+        front: 0,
+        back: slice.len(),
+        slice,
+        mid,
+        current: None,
+    }
+}
+
+/// A streaming iterator which returns the elements of a mutable slice in rotated order.
+///
+/// This struct is created by the [`rotate_left_mut`] function.
+pub struct RotateLeftMut<'a, T> {
+    slice: &'a mut [T],
+    mid: usize,
+    front: usize,
+    back: usize,
+    current: Option<usize>,
+}
+
+impl<T> RotateLeftMut<'_, T> {
+    fn index(&self, i: usize) -> usize {
+        let len = self.slice.len();
+        if i < len - self.mid {
+            i + self.mid
+        } else {
+            i - (len - self.mid)
+        }
+    }
+}
+
+impl<T> StreamingIterator for RotateLeftMut<'_, T> {
+    type Item = T;
+
+    fn advance(&mut self) {
+        if self.front < self.back {
+            self.current = Some(self.front);
+            self.front += 1;
+        } else {
+            self.current = None;
+        }
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.map(|i| &self.slice[self.index(i)])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn is_done(&self) -> bool {
+        self.current.is_none()
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ExactSizeStreamingIterator for RotateLeftMut<'_, T> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+impl<T> StreamingIteratorMut for RotateLeftMut<'_, T> {
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        let i = self.index(self.current?);
+        Some(&mut self.slice[i])
+    }
+}
+
+impl<T> DoubleEndedStreamingIterator for RotateLeftMut<'_, T> {
+    fn advance_back(&mut self) {
+        if self.front < self.back {
+            self.back -= 1;
+            self.current = Some(self.back);
+        } else {
+            self.current = None;
+        }
+    }
+}
+
+impl<T> DoubleEndedStreamingIteratorMut for RotateLeftMut<'_, T> {}
+
+/// Returns the index of the first element of a sorted `slice` for which `pred` returns `false`.
+///
+/// The slice must be partitioned by `pred`: every element for which it returns `true` must come
+/// before every element for which it returns `false`. If that does not hold, the returned index
+/// is unspecified. The search takes a logarithmic number of calls to `pred`.
+///
+/// ```
+/// # use streaming_iterator::partition_point;
+/// let items = [1, 2, 3, 5, 8];
+/// assert_eq!(partition_point(&items, |&i| i < 4), 3);
+/// ```
+#[inline]
+pub fn partition_point<T, F>(slice: &[T], pred: F) -> usize
+where
+    F: FnMut(&T) -> bool,
+{
+    slice.partition_point(pred)
+}
+
+#[test]
+fn test_windows_mut() {
+    let slice: &mut [_] = &mut [0; 6];
+
+    windows_mut(slice, 3).fold_mut(0, |i, win| {
+        win.copy_from_slice(&[i; 3]);
+        i + 1
+    });
+    assert_eq!(slice, &[0, 1, 2, 3, 3, 3]);
+
+    windows_mut(slice, 2).rfold_mut(0, |i, win| {
+        win.copy_from_slice(&[i; 2]);
+        i + 1
+    });
+    assert_eq!(slice, &[4, 4, 3, 2, 1, 0]);
+
+    let mut i = 0;
+    let mut iter = windows_mut(slice, 1);
+    while let Some(win) = iter.next_mut() {
+        win.copy_from_slice(&[i]);
+        i += 1;
+
+        if let Some(win) = iter.next_back_mut() {
+            win.copy_from_slice(&[i]);
+            i += 1;
+        }
+    }
+    assert_eq!(slice, &[0, 2, 4, 5, 3, 1]);
+}
+
+#[test]
+fn test_windows_mut_count() {
+    let slice: &mut [_] = &mut [0; 6];
+
+    assert_eq!(windows_mut(slice, 3).count(), 4);
+    assert_eq!(windows_mut(slice, 6).count(), 1);
+    assert_eq!(windows_mut(slice, 9).count(), 0);
+
+    let mut iter = windows_mut(slice, 3);
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    iter.advance();
+    assert_eq!(iter.count(), 3);
+}
+
+#[test]
+#[should_panic]
+fn test_windows_mut_0() {
+    let _: WindowsMut<'_, i32> = windows_mut(&mut [], 0);
+}
+
+#[test]
+fn test_rotate_left_mut() {
+    let slice: &mut [_] = &mut [0, 1, 2, 3, 4];
+
+    let mut order = [0; 5];
+    rotate_left_mut(slice, 2).fold(0, |i, &x| {
+        order[i] = x;
+        i + 1
+    });
+    assert_eq!(order, [2, 3, 4, 0, 1]);
+
+    rotate_left_mut(slice, 3).fold_mut(0, |i, x| {
+        *x = i;
+        i + 1
+    });
+    assert_eq!(slice, &[2, 3, 4, 0, 1]);
+
+    let mut iter = rotate_left_mut(slice, 1);
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(iter.next_back(), Some(&2));
+    assert_eq!(iter.next(), Some(&4));
+    assert_eq!(iter.count(), 2);
+
+    assert_eq!(rotate_left_mut(slice, 0).next(), Some(&2));
+    assert_eq!(rotate_left_mut(slice, 5).next(), Some(&2));
+    assert_eq!(rotate_left_mut::<i32>(&mut [], 0).next(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_rotate_left_mut_out_of_bounds() {
+    let _ = rotate_left_mut(&mut [0, 1], 3);
+}
+
+#[test]
+fn test_array_chunks() {
+    let slice = [0, 1, 2, 3, 4, 5, 6];
+
+    let mut iter = array_chunks::<_, 3>(&slice);
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(iter.remainder(), &[6]);
+    assert_eq!(iter.next(), Some(&[0, 1, 2]));
+    assert_eq!(iter.next(), Some(&[3, 4, 5]));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = array_chunks::<_, 2>(&slice);
+    assert_eq!(iter.next_back(), Some(&[4, 5]));
+    assert_eq!(iter.next(), Some(&[0, 1]));
+    assert_eq!(iter.count(), 1);
+
+    assert_eq!(array_chunks::<_, 8>(&slice).remainder(), &slice);
+    assert_eq!(array_chunks::<_, 8>(&slice).next(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_array_chunks_0() {
+    let _ = array_chunks::<i32, 0>(&[]);
+}
+
+#[test]
+fn test_for_each_window_mut() {
+    let slice: &mut [_] = &mut [0; 6];
+
+    let mut i = 0;
+    for_each_window_mut(slice, 3, |win| {
+        win.copy_from_slice(&[i; 3]);
+        i += 1;
+    });
+    assert_eq!(slice, &[0, 1, 2, 3, 3, 3]);
+
+    let mut i = 0;
+    for_each_window_mut_rev(slice, 2, |win| {
+        win.copy_from_slice(&[i; 2]);
+        i += 1;
+    });
+    assert_eq!(slice, &[4, 4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn test_transpose_slice_mut() {
+    let slice: &mut [_] = &mut [0, 1, 2, 3, 4, 5, 6];
+
+    let mut order = [0; 6];
+    transpose_slice_mut(slice, 3).fold(0, |i, &x| {
+        order[i] = x;
+        i + 1
+    });
+    assert_eq!(order, [0, 3, 1, 4, 2, 5]);
+
+    transpose_slice_mut(slice, 2).fold_mut(0, |i, x| {
+        *x = i;
+        i + 1
+    });
+    assert_eq!(slice, &[0, 3, 1, 4, 2, 5, 6]);
+
+    let mut iter = transpose_slice_mut(slice, 3);
+    assert_eq!(iter.size_hint(), (6, Some(6)));
+    assert_eq!(iter.next_back(), Some(&5));
+    assert_eq!(iter.next(), Some(&0));
+    assert_eq!(iter.count(), 4);
+
+    assert_eq!(transpose_slice_mut(slice, 8).next(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_transpose_slice_mut_0() {
+    let _ = transpose_slice_mut(&mut [0], 0);
+}
+
+#[test]
+fn test_group_runs_mut() {
+    let slice: &mut [_] = &mut [(1, 10), (1, 20), (2, 5), (3, 1), (3, 2), (3, 3)];
+
+    let mut iter = group_runs_mut(slice, |&(k, _)| k);
+    assert_eq!(iter.size_hint(), (1, Some(6)));
+    while let Some(run) = iter.next_mut() {
+        let total = run.iter().map(|&(_, v)| v).sum();
+        run[0].1 = total;
+    }
+    assert_eq!(slice, &[(1, 30), (1, 20), (2, 5), (3, 6), (3, 2), (3, 3)]);
+
+    let mut lens = [0; 3];
+    group_runs_mut(slice, |&(k, _)| k).rfold(0, |i, run| {
+        lens[i] = run.len();
+        i + 1
+    });
+    assert_eq!(lens, [3, 1, 2]);
+
+    assert_eq!(group_runs_mut(&mut [0; 0], |&x| x).next(), None);
+}
+
+#[test]
+fn test_group_adjacent_mut() {
+    let slice: &mut [_] = &mut [1, 1, 2, 3, 3, 3];
+
+    let mut lens = [0; 3];
+    let mut iter = group_adjacent_mut(slice, |a, b| a == b);
+    assert_eq!(iter.size_hint(), (1, Some(6)));
+    let mut i = 0;
+    while let Some(run) = iter.next() {
+        lens[i] = run.len();
+        i += 1;
+    }
+    assert_eq!(lens, [2, 1, 3]);
+
+    let longest = *lens.iter().max().unwrap();
+    group_adjacent_mut(slice, |a, b| a == b).for_each_mut(|run| {
+        if run.len() == longest {
+            run.fill(0);
+        }
+    });
+    assert_eq!(slice, &[1, 1, 2, 0, 0, 0]);
+
+    let mut lens = [0; 3];
+    group_adjacent_mut(slice, |a, b| a <= b).rfold(0, |i, run| {
+        lens[i] = run.len();
+        i + 1
+    });
+    assert_eq!(lens, [3, 3, 0]);
+
+    assert_eq!(group_adjacent_mut(&mut [0; 0], |a, b| a == b).next(), None);
+}
+
+#[test]
+fn test_partition_point() {
+    let items = [1, 2, 2, 3, 5];
+    assert_eq!(partition_point(&items, |&i| i < 2), 1);
+    assert_eq!(partition_point(&items, |&i| i <= 2), 3);
+    assert_eq!(partition_point(&items, |_| true), 5);
+    assert_eq!(partition_point(&items, |_| false), 0);
+    assert_eq!(partition_point(&[0; 0], |_| true), 0);
+}
+
+#[test]
+fn test_cycle_slice() {
+    let mut it = cycle_slice(&[1, 2, 3]);
+    assert_eq!(it.size_hint(), (usize::MAX, None));
+    let mut items = [0; 7];
+    for item in &mut items {
+        *item = *it.next().unwrap();
+    }
+    assert_eq!(items, [1, 2, 3, 1, 2, 3, 1]);
+
+    let mut it = cycle_slice(&[1, 2, 3]);
+    for item in &mut items {
+        *item = *it.next_back().unwrap();
+    }
+    assert_eq!(items, [3, 2, 1, 3, 2, 1, 3]);
+
+    let mut it = cycle_slice::<u8>(&[]);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+}
+
+#[test]
+fn test_cycle_slice_ref_iter() {
+    let mut it = cycle_slice(&[1, 2, 3]);
+    it.advance();
+    let items: [&i32; 5] = {
+        let mut ref_iter = it.ref_iter();
+        let mut items = [&0; 5];
+        for item in &mut items {
+            *item = ref_iter.next().unwrap();
+        }
+        items
+    };
+    assert_eq!(items, [&2, &3, &1, &2, &3]);
+    assert_eq!(cycle_slice::<u8>(&[]).ref_iter().next(), None);
+}
+
+#[test]
+fn test_windows_mut_index() {
+    let mut items = [0, 1, 2, 3, 4, 5];
+    let mut it = windows_mut(&mut items, 3);
+    assert_eq!(it.index(), None);
+    it.advance();
+    assert_eq!(it.index(), Some(0));
+    assert_eq!(it.get(), Some(&[0, 1, 2][..]));
+    it.advance_back();
+    assert_eq!(it.index(), Some(3));
+    assert_eq!(it.get(), Some(&[3, 4, 5][..]));
+    it.advance();
+    assert_eq!(it.index(), Some(1));
+    assert_eq!(it.get(), Some(&[1, 2, 3][..]));
+    it.advance_back();
+    assert_eq!(it.index(), Some(2));
+    assert_eq!(it.get(), Some(&[2, 3, 4][..]));
+    it.advance();
+    assert_eq!(it.index(), None);
+    assert_eq!(it.get(), None);
+
+    let mut it = windows_mut(&mut items, 2);
+    let mut indices = [0; 5];
+    let mut i = 0;
+    while it.next_mut().is_some() {
+        indices[i] = it.index().unwrap();
+        i += 1;
+    }
+    assert_eq!(indices, [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_windows() {
+    let slice = &[0, 1, 2, 3, 4];
+
+    let sum = windows(slice, 3).fold(0, |acc, win| acc * 10 + win.iter().sum::<i32>());
+    assert_eq!(sum, 369);
+
+    let firsts = windows(slice, 2).rfold(0, |acc, win| acc * 10 + win[0]);
+    assert_eq!(firsts, 3210);
+
+    let mut iter = windows(slice, 2);
+    assert_eq!(iter.next(), Some(&[0, 1][..]));
+    assert_eq!(iter.index(), Some(0));
+    assert_eq!(iter.next_back(), Some(&[3, 4][..]));
+    assert_eq!(iter.index(), Some(3));
+    assert_eq!(iter.next(), Some(&[1, 2][..]));
+    assert_eq!(iter.next_back(), Some(&[2, 3][..]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.index(), None);
+}
+
+#[test]
+fn test_windows_count() {
+    let slice = &[0; 6];
+
+    assert_eq!(windows(slice, 3).count(), 4);
+    assert_eq!(windows(slice, 6).count(), 1);
+    assert_eq!(windows(slice, 9).count(), 0);
+
+    let mut iter = windows(slice, 3);
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    iter.advance();
+    assert_eq!(iter.count(), 3);
+}
+
+#[test]
+#[should_panic]
+fn test_windows_0() {
+    let _: Windows<'_, i32> = windows(&[], 0);
+}
+
+#[test]
+fn test_chunks_mut() {
+    let slice: &mut [_] = &mut [0; 7];
+
+    chunks_mut(slice, 3).fold_mut(1, |i, chunk| {
+        for x in chunk.iter_mut() {
+            *x = i;
+        }
+        i + 1
+    });
+    assert_eq!(slice, &[1, 1, 1, 2, 2, 2, 3]);
+
+    chunks_mut(slice, 3).rfold_mut(0, |i, chunk| {
+        chunk[0] = i;
+        i + 1
+    });
+    assert_eq!(slice, &[2, 1, 1, 1, 2, 2, 0]);
+
+    let mut iter = chunks_mut(slice, 2);
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.next_back_mut(), Some(&mut [0][..]));
+    assert_eq!(iter.next_mut(), Some(&mut [2, 1][..]));
+    assert_eq!(iter.count(), 2);
+
+    assert_eq!(chunks_mut(slice, 7).count(), 1);
+    assert_eq!(chunks_mut(slice, 8).count(), 1);
+    assert_eq!(chunks_mut(&mut [0; 0], 8).count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_chunks_mut_0() {
+    let _: ChunksMut<'_, i32> = chunks_mut(&mut [], 0);
+}
+
+#[test]
+fn test_rchunks_mut() {
+    let slice: &mut [_] = &mut [1, 2, 3, 4, 5];
+
+    let mut iter = rchunks_mut(slice, 2);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(iter.next_mut(), Some(&mut [4, 5][..]));
+    assert_eq!(iter.next_mut(), Some(&mut [2, 3][..]));
+    assert_eq!(iter.next_mut(), Some(&mut [1][..]));
+    assert_eq!(iter.next_mut(), None);
+
+    let mut iter = rchunks_mut(slice, 2);
+    assert_eq!(iter.next_back(), Some(&[1][..]));
+    assert_eq!(iter.next(), Some(&[4, 5][..]));
+    assert_eq!(iter.next_back(), Some(&[2, 3][..]));
+    assert_eq!(iter.next_back(), None);
+
+    rchunks_mut(slice, 2).for_each_mut(|chunk| chunk.reverse());
+    assert_eq!(slice, &[1, 3, 2, 5, 4]);
+
+    assert_eq!(rchunks_mut(slice, 5).count(), 1);
+    assert_eq!(rchunks_mut(&mut [0; 0], 2).count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_rchunks_mut_0() {
+    let _: RChunksMut<'_, i32> = rchunks_mut(&mut [], 0);
+}
+
+#[test]
+fn test_exact_size() {
+    let slice: &mut [_] = &mut [0; 7];
+    assert_eq!(windows(slice, 3).len(), 5);
+    assert_eq!(windows_mut(slice, 3).len(), 5);
+    assert_eq!(chunks_mut(slice, 3).len(), 3);
+    assert_eq!(rchunks_mut(slice, 3).len(), 3);
+    assert_eq!(array_chunks::<_, 3>(slice).len(), 2);
+    assert_eq!(transpose_slice_mut(slice, 3).len(), 6);
+    assert_eq!(rotate_left_mut(slice, 3).len(), 7);
+
+    let mut iter = chunks_mut(slice, 3);
+    iter.advance_back();
+    assert_eq!(iter.len(), 2);
+    assert!(!iter.is_empty());
+    iter.advance();
+    iter.advance();
+    assert!(iter.is_empty());
+}
+
+#[test]
+fn test_split_mut() {
+    fn collect(iter: impl StreamingIterator<Item = [i32]>) -> ([[i32; 2]; 4], [usize; 4]) {
+        let mut items = [[0; 2]; 4];
+        let mut lens = [0; 4];
+        iter.fold(0, |i, s| {
+            items[i][..s.len()].copy_from_slice(s);
+            lens[i] = s.len();
+            i + 1
+        });
+        (items, lens)
+    }
+
+    let slice: &mut [_] = &mut [1, 0, 2, 3, 0, 4];
+    let (items, lens) = collect(split_mut(slice, |&x| x == 0));
+    assert_eq!(lens, [1, 2, 1, 0]);
+    assert_eq!(items[..3], [[1, 0], [2, 3], [4, 0]]);
+
+    let (items, lens) = collect(split_mut(slice, |&x| x == 0).rev());
+    assert_eq!(lens, [1, 2, 1, 0]);
+    assert_eq!(items[..3], [[4, 0], [2, 3], [1, 0]]);
+
+    let mut iter = split_mut(slice, |&x| x == 0);
+    assert_eq!(iter.next(), Some(&[1][..]));
+    assert_eq!(iter.next_back(), Some(&[4][..]));
+    iter.next_mut().unwrap()[1] = 5;
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(slice, &[1, 0, 2, 5, 0, 4]);
+
+    let slice: &mut [_] = &mut [0, 1, 0, 0];
+    let (items, lens) = collect(split_mut(slice, |&x| x == 0));
+    assert_eq!(lens, [0, 1, 0, 0]);
+    assert_eq!(items[1], [1, 0]);
+    assert_eq!(split_mut(slice, |&x| x == 0).count(), 4);
+    assert_eq!(split_mut(slice, |&x| x == 0).rev().count(), 4);
+
+    assert_eq!(split_mut(&mut [0; 0], |&x| x == 0).count(), 1);
+}
+
+#[test]
+fn test_array_windows_mut() {
+    let slice: &mut [_] = &mut [1, 2, 3, 4];
+
+    let mut iter = array_windows_mut::<_, 2>(slice);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    let mut sums = [0; 3];
+    let mut i = 0;
+    while let Some(&[a, b]) = iter.next() {
+        sums[i] = a + b;
+        i += 1;
+    }
+    assert_eq!(sums, [3, 5, 7]);
+
+    let mut iter = array_windows_mut::<_, 2>(slice);
+    while let Some([a, b]) = iter.next_back_mut() {
+        *a += *b;
+    }
+    assert_eq!(slice, &[10, 9, 7, 4]);
+
+    let mut iter = array_windows_mut::<_, 3>(slice);
+    assert_eq!(iter.next(), Some(&[10, 9, 7]));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some(&[9, 7, 4]));
+    assert_eq!(iter.next(), None);
+
+    assert_eq!(array_windows_mut::<_, 5>(slice).count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_array_windows_mut_0() {
+    let _: ArrayWindowsMut<'_, i32, 0> = array_windows_mut(&mut []);
+}
+
+#[test]
+fn test_windows_mut_step() {
+    let slice: &mut [_] = &mut [0; 6];
+
+    let mut iter = windows_mut_step(slice, 2, 2);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    let mut offsets = [0; 3];
+    let mut i = 0;
+    while let Some(window) = iter.next_mut() {
+        assert_eq!(window.len(), 2);
+        window[0] += 1;
+        offsets[i] = iter.index().unwrap();
+        i += 1;
+        assert_eq!(iter.len(), 3 - i);
+    }
+    assert_eq!(offsets, [0, 2, 4]);
+    assert_eq!(iter.index(), None);
+    assert_eq!(slice, &[1, 0, 1, 0, 1, 0]);
+
+    assert_eq!(windows_mut_step(slice, 3, 2).count(), 2);
+    assert_eq!(windows_mut_step(slice, 1, 4).count(), 2);
+    assert_eq!(windows_mut_step(slice, 6, 1).count(), 1);
+    assert_eq!(windows_mut_step(slice, 7, 1).count(), 0);
+    assert_eq!(windows_mut_step(slice, 2, 1).count(), 5);
+
+    let mut iter = windows_mut_step(slice, 1, 4);
+    iter.advance();
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next().map(<[_]>::len), Some(1));
+    assert_eq!(iter.index(), Some(4));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+#[should_panic]
+fn test_windows_mut_step_0() {
+    let _ = windows_mut_step(&mut [1, 2], 1, 0);
+}
+
+#[test]
+fn test_windows_mut_alternating() {
+    let slice: &mut [_] = &mut [0, 1, 2, 3, 4, 5];
+    let mut iter = windows_mut(slice, 2);
+    assert_eq!(iter.next_mut(), Some(&mut [0, 1][..]));
+    assert_eq!(iter.next_back_mut(), Some(&mut [4, 5][..]));
+    assert_eq!(iter.get_mut(), Some(&mut [4, 5][..]));
+    assert_eq!(iter.next_mut(), Some(&mut [1, 2][..]));
+    assert_eq!(iter.next_back(), Some(&[3, 4][..]));
+    assert_eq!(iter.get(), Some(&[3, 4][..]));
+    let window = iter.next_mut().unwrap();
+    assert_eq!(window, &mut [2, 3][..]);
+    window[0] = 20;
+    assert_eq!(iter.next_back_mut(), None);
+    assert_eq!(iter.next_mut(), None);
+    assert_eq!(slice, &[0, 1, 20, 3, 4, 5]);
+}
+
+#[test]
+fn test_windows_get_at() {
+    let items = [0, 1, 2, 3, 4];
+    for start in 0..4 {
+        let mut iter = windows(&items, 2);
+        for _ in 0..start {
+            iter.advance();
+        }
+        for k in 0..5 {
+            let mut stepped = iter.clone();
+            for _ in 0..=k {
+                stepped.advance();
+            }
+            assert_eq!(iter.get_at(k), stepped.get());
+            assert_eq!(iter.clone().nth(k), stepped.get());
+        }
+    }
+
+    let mut iter = windows(&items, 2);
+    assert_eq!(iter.nth(2), Some(&[2, 3][..]));
+    assert_eq!(iter.index(), Some(2));
+    assert_eq!(iter.next_back(), Some(&[3, 4][..]));
+    assert_eq!(iter.nth(0), None);
+}
+
+#[test]
+fn test_windows_mut_get_at() {
+    fn start(items: &mut [i32], steps: usize, back: bool) -> WindowsMut<'_, i32> {
+        let mut iter = windows_mut(items, 2);
+        for _ in 0..steps {
+            if back {
+                iter.advance_back();
+            } else {
+                iter.advance();
+            }
+        }
+        iter
+    }
+
+    let mut items = [0, 1, 2, 3, 4];
+    for back in [false, true] {
+        for steps in 0..5 {
+            for k in 0..5 {
+                let at = start(&mut items, steps, back)
+                    .get_at(k)
+                    .map(|w| [w[0], w[1]]);
+                let mut stepped = start(&mut items, steps, back);
+                for _ in 0..=k {
+                    stepped.advance();
+                }
+                assert_eq!(at, stepped.get().map(|w| [w[0], w[1]]));
+                let nth = start(&mut items, steps, back).nth(k).map(|w| [w[0], w[1]]);
+                assert_eq!(at, nth);
+            }
+        }
+    }
+
+    let mut iter = windows_mut(&mut items, 2);
+    assert_eq!(iter.nth(2), Some(&[2, 3][..]));
+    assert_eq!(iter.index(), Some(2));
+    assert_eq!(iter.get_at(0), Some(&[3, 4][..]));
+    iter.get_mut().unwrap()[0] = 10;
+    assert_eq!(iter.next(), Some(&[3, 4][..]));
+    assert_eq!(iter.nth(5), None);
+    assert_eq!(items, [0, 1, 10, 3, 4]);
 }
@@ -1,7 +1,13 @@
-use super::{DoubleEndedStreamingIterator, StreamingIterator};
+use super::StreamingIterator;
+use super::{
+    AsRefIterator, DoubleEndedStreamingIterator, ExactSizeStreamingIterator,
+    IndexedStreamingIterator,
+};
 use super::{DoubleEndedStreamingIteratorMut, StreamingIteratorMut};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::marker::PhantomData;
-use core::usize;
+use core::slice;
 
 /// Turns a normal, non-streaming iterator into a streaming iterator.
 ///
@@ -24,6 +30,37 @@ where
     }
 }
 
+/// Creates an iterator which yields the elements of each iterator in `iters` in sequence.
+///
+/// This is equivalent to chaining the iterators together with `chain`, but without nesting a
+/// `Chain` type for every iterator.
+///
+/// Requires the `alloc` feature.
+///
+/// ```
+/// # use streaming_iterator::{StreamingIterator, chain_many, convert};
+/// let mut streaming_iter = chain_many(vec![convert(0..2), convert(2..2), convert(2..4)]);
+/// assert_eq!(streaming_iter.size_hint(), (4, Some(4)));
+/// assert_eq!(streaming_iter.next(), Some(&0));
+/// assert_eq!(streaming_iter.next(), Some(&1));
+/// assert_eq!(streaming_iter.next(), Some(&2));
+/// assert_eq!(streaming_iter.next(), Some(&3));
+/// assert_eq!(streaming_iter.next(), None);
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn chain_many<I>(iters: Vec<I>) -> ChainMany<I>
+where
+    I: StreamingIterator,
+{
+    ChainMany {
+        front: 0,
+        back: iters.len(),
+        iters,
+        current: None,
+    }
+}
+
 /// Turns an iterator of references into a streaming iterator.
 ///
 /// ```
@@ -45,6 +82,36 @@ where
     }
 }
 
+/// Turns a container, such as an array or a `Vec`, into a streaming iterator over references to
+/// its elements.
+///
+/// Unlike `convert_ref`, the returned iterator owns the container, so it does not borrow from a
+/// separate binding.
+///
+/// ```
+/// # use streaming_iterator::{StreamingIterator, convert_into_ref};
+/// fn scores() -> impl StreamingIterator<Item = u32> {
+///     convert_into_ref(vec![100, 50, 80])
+/// }
+/// let mut streaming_iter = scores();
+/// while let Some(score) = streaming_iter.next() {
+///     println!("The score is: {}", score);
+/// }
+/// ```
+#[inline]
+pub fn convert_into_ref<C, T>(container: C) -> ConvertIntoRef<C, T>
+where
+    C: AsRef<[T]>,
+{
+    ConvertIntoRef {
+        back: container.as_ref().len(),
+        container,
+        front: 0,
+        current: None,
+        _marker: PhantomData,
+    }
+}
+
 /// Turns an iterator of mutable references into a streaming iterator.
 ///
 /// ```
@@ -105,6 +172,29 @@ pub fn from_fn<T, F: FnMut() -> Option<T>>(gen: F) -> FromFn<T, F> {
     FromFn { gen, item: None }
 }
 
+/// Creates an iterator which endlessly applies a function to the preceding item, starting with
+/// `first`.
+///
+/// Unlike `successors`, the function borrows the preceding item, so it does not need to be
+/// reconstructed.
+///
+/// ```
+/// # use streaming_iterator::StreamingIterator;
+/// let mut streaming_iter = streaming_iterator::iterate(1, |i| i * 2);
+/// assert_eq!(streaming_iter.next(), Some(&1));
+/// assert_eq!(streaming_iter.next(), Some(&2));
+/// assert_eq!(streaming_iter.next(), Some(&4));
+/// // ...
+/// ```
+#[inline]
+pub fn iterate<T, F: FnMut(&T) -> T>(first: T, f: F) -> Iterate<T, F> {
+    Iterate {
+        first: true,
+        item: first,
+        f,
+    }
+}
+
 /// Creates an iterator that returns exactly one item.
 ///
 /// ```
@@ -139,6 +229,29 @@ pub fn once_with<T, F: FnOnce() -> T>(gen: F) -> OnceWith<T, F> {
     }
 }
 
+/// Creates an iterator over the integers from `start` (inclusive) to `end` (exclusive).
+///
+/// This behaves like `convert(start..end)`, but keeps the current value directly rather than in an
+/// `Option`.
+///
+/// ```
+/// # use streaming_iterator::{DoubleEndedStreamingIterator, StreamingIterator};
+/// let mut streaming_iter = streaming_iterator::range(1u8, 4);
+/// assert_eq!(streaming_iter.next(), Some(&1));
+/// assert_eq!(streaming_iter.next_back(), Some(&3));
+/// assert_eq!(streaming_iter.next(), Some(&2));
+/// assert_eq!(streaming_iter.next(), None);
+/// ```
+#[inline]
+pub fn range<T: Step>(start: T, end: T) -> Range<T> {
+    Range {
+        front: start,
+        back: end,
+        current: start,
+        valid: false,
+    }
+}
+
 /// Creates an iterator that returns an item endlessly.
 ///
 /// ```
@@ -156,6 +269,24 @@ pub fn repeat<T>(item: T) -> Repeat<T> {
     Repeat { item }
 }
 
+/// Creates an iterator that returns an item exactly `n` times.
+///
+/// ```
+/// # use streaming_iterator::StreamingIterator;
+/// let mut streaming_iter = streaming_iterator::repeat_n("a", 2);
+/// assert_eq!(streaming_iter.next(), Some(&"a"));
+/// assert_eq!(streaming_iter.next(), Some(&"a"));
+/// assert_eq!(streaming_iter.next(), None);
+/// ```
+#[inline]
+pub fn repeat_n<T>(item: T, n: usize) -> RepeatN<T> {
+    RepeatN {
+        item,
+        remaining: n,
+        current: false,
+    }
+}
+
 /// Creates an iterator that endlessly returns items from a function call.
 ///
 /// ```
@@ -199,6 +330,70 @@ pub fn successors<T, F: FnMut(T) -> Option<T>>(first: Option<T>, succ: F) -> Suc
     }
 }
 
+/// Creates an iterator where each successive item is computed from the preceding one, yielding
+/// at most `max_len` items.
+///
+/// This is the same iterator as `successors_n`, named for the common case where `succ` is expected
+/// to end iteration itself and `max_len` only guards against running forever.
+///
+/// ```
+/// # use streaming_iterator::StreamingIterator;
+/// let mut streaming_iter = streaming_iterator::successors_bounded(Some(1), 10, |i| {
+///     if i < 3 {
+///         Some(i + 1)
+///     } else {
+///         None
+///     }
+/// });
+/// assert_eq!(streaming_iter.size_hint(), (1, Some(10)));
+/// assert_eq!(streaming_iter.next(), Some(&1));
+/// assert_eq!(streaming_iter.size_hint(), (0, Some(9)));
+/// assert_eq!(streaming_iter.next(), Some(&2));
+/// assert_eq!(streaming_iter.next(), Some(&3));
+/// assert_eq!(streaming_iter.next(), None);
+/// ```
+#[inline]
+pub fn successors_bounded<T, F: FnMut(T) -> Option<T>>(
+    first: Option<T>,
+    max_len: usize,
+    succ: F,
+) -> SuccessorsBounded<T, F> {
+    successors_n(first, max_len, succ)
+}
+
+/// Creates an iterator where each successive item is computed from the preceding one, yielding
+/// at most `n` items.
+///
+/// Iteration ends early if `succ` returns `None`, so `n` is only an upper bound on the number of
+/// items and the iterator does not implement `ExactSizeStreamingIterator`.
+///
+/// Successors can only be computed front to back, so this iterator does not implement
+/// `DoubleEndedStreamingIterator` either.
+///
+/// ```
+/// # use streaming_iterator::StreamingIterator;
+/// let mut streaming_iter = streaming_iterator::successors_n(Some(1), 3, |i| Some(i * 10));
+/// assert_eq!(streaming_iter.size_hint(), (1, Some(3)));
+/// assert_eq!(streaming_iter.next(), Some(&1));
+/// assert_eq!(streaming_iter.next(), Some(&10));
+/// assert_eq!(streaming_iter.size_hint(), (0, Some(1)));
+/// assert_eq!(streaming_iter.next(), Some(&100));
+/// assert_eq!(streaming_iter.next(), None);
+/// ```
+#[inline]
+pub fn successors_n<T, F: FnMut(T) -> Option<T>>(
+    first: Option<T>,
+    n: usize,
+    succ: F,
+) -> SuccessorsN<T, F> {
+    SuccessorsN {
+        first: true,
+        remaining: if first.is_some() { n } else { 0 },
+        item: first,
+        succ,
+    }
+}
+
 /// A streaming iterator which yields elements from a normal, non-streaming, iterator.
 #[derive(Clone, Debug)]
 pub struct Convert<I>
@@ -235,6 +430,36 @@ where
         self.it.count()
     }
 
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&I::Item> {
+        self.item = self.it.nth(n);
+        self.item.as_ref()
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), usize> {
+        if n == 0 {
+            return Ok(());
+        }
+        // The shortfall is only known without stepping when the remaining length is.
+        let (lower, upper) = self.it.size_hint();
+        if lower >= n || upper == Some(lower) {
+            self.item = self.it.nth(n - 1);
+            return match self.item {
+                Some(_) => Ok(()),
+                // A size hint which overstates the remaining length must not underflow here.
+                None => Err(n.saturating_sub(lower).max(1)),
+            };
+        }
+        for i in 0..n {
+            self.advance();
+            if self.item.is_none() {
+                return Err(n - i);
+            }
+        }
+        Ok(())
+    }
+
     #[inline]
     fn fold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
     where
@@ -254,6 +479,35 @@ where
         self.item = self.it.next_back();
     }
 
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<&I::Item> {
+        self.item = self.it.nth_back(n);
+        self.item.as_ref()
+    }
+
+    #[inline]
+    fn advance_back_by(&mut self, n: usize) -> Result<(), usize> {
+        if n == 0 {
+            return Ok(());
+        }
+        let (lower, upper) = self.it.size_hint();
+        if lower >= n || upper == Some(lower) {
+            self.item = self.it.nth_back(n - 1);
+            return match self.item {
+                Some(_) => Ok(()),
+                // A size hint which overstates the remaining length must not underflow here.
+                None => Err(n.saturating_sub(lower).max(1)),
+            };
+        }
+        for i in 0..n {
+            self.advance_back();
+            if self.item.is_none() {
+                return Err(n - i);
+            }
+        }
+        Ok(())
+    }
+
     #[inline]
     fn rfold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
     where
@@ -264,6 +518,16 @@ where
     }
 }
 
+impl<I> ExactSizeStreamingIterator for Convert<I>
+where
+    I: ExactSizeIterator,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.it.len()
+    }
+}
+
 impl<I> StreamingIteratorMut for Convert<I>
 where
     I: Iterator,
@@ -297,6 +561,153 @@ where
     }
 }
 
+/// A streaming iterator which yields the elements of a sequence of streaming iterators in order.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct ChainMany<I> {
+    iters: Vec<I>,
+    front: usize,
+    back: usize,
+    current: Option<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl<I> StreamingIterator for ChainMany<I>
+where
+    I: StreamingIterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        while self.front < self.back {
+            let it = &mut self.iters[self.front];
+            it.advance();
+            if !it.is_done() {
+                self.current = Some(self.front);
+                return;
+            }
+            self.front += 1;
+        }
+        self.current = None;
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        match self.current {
+            Some(i) => self.iters[i].is_done(),
+            None => true,
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&I::Item> {
+        self.current.and_then(|i| self.iters[i].get())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iters[self.front..self.back]
+            .iter()
+            .fold((0, Some(0)), |(lower, upper), it| {
+                let (l, u) = it.size_hint();
+                let upper = match (upper, u) {
+                    (Some(a), Some(b)) => a.checked_add(b),
+                    _ => None,
+                };
+                (lower.saturating_add(l), upper)
+            })
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(mut self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        self.iters
+            .drain(self.front..self.back)
+            .fold(init, |acc, it| it.fold(acc, &mut f))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> DoubleEndedStreamingIterator for ChainMany<I>
+where
+    I: DoubleEndedStreamingIterator,
+{
+    #[inline]
+    fn advance_back(&mut self) {
+        while self.front < self.back {
+            let it = &mut self.iters[self.back - 1];
+            it.advance_back();
+            if !it.is_done() {
+                self.current = Some(self.back - 1);
+                return;
+            }
+            self.back -= 1;
+        }
+        self.current = None;
+    }
+
+    #[inline]
+    fn rfold<Acc, Fold>(mut self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        self.iters
+            .drain(self.front..self.back)
+            .rev()
+            .fold(init, |acc, it| it.rfold(acc, &mut f))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> StreamingIteratorMut for ChainMany<I>
+where
+    I: StreamingIteratorMut,
+{
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut I::Item> {
+        match self.current {
+            Some(i) => self.iters[i].get_mut(),
+            None => None,
+        }
+    }
+
+    #[inline]
+    fn fold_mut<Acc, Fold>(mut self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        self.iters
+            .drain(self.front..self.back)
+            .fold(init, |acc, it| it.fold_mut(acc, &mut f))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> DoubleEndedStreamingIteratorMut for ChainMany<I>
+where
+    I: DoubleEndedStreamingIteratorMut,
+{
+    #[inline]
+    fn rfold_mut<Acc, Fold>(mut self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        self.iters
+            .drain(self.front..self.back)
+            .rev()
+            .fold(init, |acc, it| it.rfold_mut(acc, &mut f))
+    }
+}
+
 /// A streaming iterator which yields elements from an iterator of references.
 #[derive(Clone, Debug)]
 pub struct ConvertRef<'a, I, T: ?Sized>
@@ -333,6 +744,12 @@ where
         self.it.count()
     }
 
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&T> {
+        self.item = self.it.nth(n);
+        self.item
+    }
+
     #[inline]
     fn fold<Acc, Fold>(self, init: Acc, f: Fold) -> Acc
     where
@@ -352,6 +769,12 @@ where
         self.item = self.it.next_back();
     }
 
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<&T> {
+        self.item = self.it.nth_back(n);
+        self.item
+    }
+
     #[inline]
     fn rfold<Acc, Fold>(self, init: Acc, f: Fold) -> Acc
     where
@@ -362,6 +785,119 @@ where
     }
 }
 
+impl<'a, I, T: ?Sized> ExactSizeStreamingIterator for ConvertRef<'a, I, T>
+where
+    I: ExactSizeIterator<Item = &'a T>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.it.len()
+    }
+}
+
+impl<'a, I, T: ?Sized> AsRefIterator<'a> for ConvertRef<'a, I, T>
+where
+    I: Iterator<Item = &'a T>,
+{
+    type RefIter = I;
+
+    #[inline]
+    fn ref_iter(self) -> I {
+        self.it
+    }
+}
+
+impl<'a, T> IndexedStreamingIterator for ConvertRef<'a, slice::Iter<'a, T>, T> {
+    #[inline]
+    fn get_at(&self, index: usize) -> Option<&T> {
+        self.it.as_slice().get(index)
+    }
+}
+
+impl<'a, T> ConvertRef<'a, slice::Iter<'a, T>, T> {
+    /// Advances the iterator to the first remaining element for which `pred` returns `false`,
+    /// returning it.
+    ///
+    /// This is a specialization of `StreamingIterator::skip_to` for iterators over a
+    /// contiguous slice, such as those created by `convert_ref(&slice)`, which finds the element
+    /// with a binary search rather than a linear scan. The remaining elements must be partitioned
+    /// by `pred` as described in `partition_point`.
+    ///
+    /// ```
+    /// # use streaming_iterator::{convert_ref, StreamingIterator};
+    /// let items = [1, 2, 3, 5, 8];
+    /// let mut it = convert_ref(&items);
+    /// assert_eq!(it.skip_to(|&i| i < 4), Some(&5));
+    /// assert_eq!(it.next(), Some(&8));
+    /// ```
+    #[inline]
+    pub fn skip_to<F>(&mut self, pred: F) -> Option<&T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let slice = self.it.as_slice();
+        let i = crate::slice::partition_point(slice, pred);
+        self.it = slice[i..].iter();
+        self.advance();
+        self.item
+    }
+}
+
+/// A streaming iterator which yields references to the elements of a container it owns.
+#[derive(Clone, Debug)]
+pub struct ConvertIntoRef<C, T> {
+    container: C,
+    front: usize,
+    back: usize,
+    current: Option<usize>,
+    _marker: PhantomData<T>,
+}
+
+impl<C, T> StreamingIterator for ConvertIntoRef<C, T>
+where
+    C: AsRef<[T]>,
+{
+    type Item = T;
+
+    #[inline]
+    fn advance(&mut self) {
+        if self.front < self.back {
+            self.current = Some(self.front);
+            self.front += 1;
+        } else {
+            self.current = None;
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&T> {
+        self.current.map(|i| &self.container.as_ref()[i])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<C, T> DoubleEndedStreamingIterator for ConvertIntoRef<C, T>
+where
+    C: AsRef<[T]>,
+{
+    #[inline]
+    fn advance_back(&mut self) {
+        if self.front < self.back {
+            self.back -= 1;
+            self.current = Some(self.back);
+        } else {
+            self.current = None;
+        }
+    }
+}
+
+impl<C, T> ExactSizeStreamingIterator for ConvertIntoRef<C, T> where C: AsRef<[T]> {}
+
 /// A streaming iterator which yields elements from an iterator of mutable references.
 #[derive(Debug)]
 pub struct ConvertMut<'a, I, T: ?Sized>
@@ -401,6 +937,12 @@ where
         self.it.count()
     }
 
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&T> {
+        self.item = self.it.nth(n);
+        (*self).get()
+    }
+
     #[inline]
     fn fold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
     where
@@ -420,6 +962,12 @@ where
         self.item = self.it.next_back();
     }
 
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<&T> {
+        self.item = self.it.nth_back(n);
+        (*self).get()
+    }
+
     #[inline]
     fn rfold<Acc, Fold>(self, init: Acc, mut f: Fold) -> Acc
     where
@@ -430,6 +978,16 @@ where
     }
 }
 
+impl<'a, I, T: ?Sized> ExactSizeStreamingIterator for ConvertMut<'a, I, T>
+where
+    I: ExactSizeIterator<Item = &'a mut T>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.it.len()
+    }
+}
+
 impl<'a, I, T: ?Sized> StreamingIteratorMut for ConvertMut<'a, I, T>
 where
     I: Iterator<Item = &'a mut T>,
@@ -466,6 +1024,37 @@ where
     }
 }
 
+impl<'a, T> ConvertMut<'a, slice::IterMut<'a, T>, T> {
+    /// Retains only the elements matching a predicate, returning how many were retained.
+    ///
+    /// This only works on iterators over a contiguous slice, such as those created by
+    /// `convert_mut(&mut slice)`. The elements that have not yet been yielded are compacted in
+    /// place: retained elements are shifted to the front of that part of the slice, preserving
+    /// their order, and the removed elements are left after them in an unspecified order, much
+    /// like `Vec::retain` before truncation.
+    ///
+    /// ```
+    /// # use streaming_iterator::convert_mut;
+    /// let mut items = [1, 2, 3, 4, 5, 6];
+    /// let len = convert_mut(&mut items).retain_in_place(|&i| i % 3 != 0);
+    /// assert_eq!(&items[..len], &[1, 2, 4, 5]);
+    /// ```
+    pub fn retain_in_place<F>(self, mut f: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let slice = self.it.into_slice();
+        let mut retained = 0;
+        for i in 0..slice.len() {
+            if f(&slice[i]) {
+                slice.swap(retained, i);
+                retained += 1;
+            }
+        }
+        retained
+    }
+}
+
 /// A simple iterator that returns nothing.
 #[derive(Clone, Debug)]
 pub struct Empty<T> {
@@ -494,6 +1083,8 @@ impl<T> DoubleEndedStreamingIterator for Empty<T> {
     fn advance_back(&mut self) {}
 }
 
+impl<T> ExactSizeStreamingIterator for Empty<T> {}
+
 impl<T> StreamingIteratorMut for Empty<T> {
     #[inline]
     fn get_mut(&mut self) -> Option<&mut Self::Item> {
@@ -522,6 +1113,19 @@ impl<T, F: FnMut() -> Option<T>> StreamingIterator for FromFn<T, F> {
     fn get(&self) -> Option<&Self::Item> {
         self.item.as_ref()
     }
+
+    #[inline]
+    fn fold<Acc, Fold>(mut self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        while let Some(item) = (self.gen)() {
+            acc = f(acc, &item);
+        }
+        acc
+    }
 }
 
 impl<T, F: FnMut() -> Option<T>> StreamingIteratorMut for FromFn<T, F> {
@@ -529,6 +1133,68 @@ impl<T, F: FnMut() -> Option<T>> StreamingIteratorMut for FromFn<T, F> {
     fn get_mut(&mut self) -> Option<&mut Self::Item> {
         self.item.as_mut()
     }
+
+    #[inline]
+    fn fold_mut<Acc, Fold>(mut self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        while let Some(mut item) = (self.gen)() {
+            acc = f(acc, &mut item);
+        }
+        acc
+    }
+}
+
+/// An iterator which endlessly applies a function to the preceding item.
+///
+/// Note: if an item is modified through `StreamingIteratorMut`, those changes
+/// will be visible to the function when the iterator is advanced.
+#[derive(Clone, Debug)]
+pub struct Iterate<T, F> {
+    first: bool,
+    item: T,
+    f: F,
+}
+
+impl<T, F: FnMut(&T) -> T> StreamingIterator for Iterate<T, F> {
+    type Item = T;
+
+    #[inline]
+    fn advance(&mut self) {
+        if self.first {
+            self.first = false;
+        } else {
+            self.item = (self.f)(&self.item);
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&Self::Item> {
+        if self.first {
+            None
+        } else {
+            Some(&self.item)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T, F: FnMut(&T) -> T> StreamingIteratorMut for Iterate<T, F> {
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        if self.first {
+            None
+        } else {
+            Some(&mut self.item)
+        }
+    }
 }
 
 /// A simple iterator that returns exactly one item.
@@ -569,6 +1235,8 @@ impl<T> DoubleEndedStreamingIterator for Once<T> {
     }
 }
 
+impl<T> ExactSizeStreamingIterator for Once<T> {}
+
 impl<T> StreamingIteratorMut for Once<T> {
     #[inline]
     fn get_mut(&mut self) -> Option<&mut Self::Item> {
@@ -612,6 +1280,8 @@ impl<T, F: FnOnce() -> T> DoubleEndedStreamingIterator for OnceWith<T, F> {
     }
 }
 
+impl<T, F: FnOnce() -> T> ExactSizeStreamingIterator for OnceWith<T, F> {}
+
 impl<T, F: FnOnce() -> T> StreamingIteratorMut for OnceWith<T, F> {
     #[inline]
     fn get_mut(&mut self) -> Option<&mut Self::Item> {
@@ -621,6 +1291,114 @@ impl<T, F: FnOnce() -> T> StreamingIteratorMut for OnceWith<T, F> {
 
 impl<T, F: FnOnce() -> T> DoubleEndedStreamingIteratorMut for OnceWith<T, F> {}
 
+/// Integer types which can be iterated over by `range`.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Step: Copy + PartialOrd + private::Sealed {
+    /// Returns the value following `self`.
+    fn forward(self) -> Self;
+
+    /// Returns the value preceding `self`.
+    fn backward(self) -> Self;
+
+    /// Returns the number of values from `start` (inclusive) to `end` (exclusive), or `None` if it
+    /// does not fit in a `usize`.
+    fn steps_between(start: Self, end: Self) -> Option<usize>;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_step {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl Step for $t {
+                #[inline]
+                fn forward(self) -> Self {
+                    self + 1
+                }
+
+                #[inline]
+                fn backward(self) -> Self {
+                    self - 1
+                }
+
+                #[inline]
+                fn steps_between(start: Self, end: Self) -> Option<usize> {
+                    if start < end {
+                        usize::try_from(end.wrapping_sub(start) as $u).ok()
+                    } else {
+                        Some(0)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_step!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+);
+
+/// A streaming iterator over a range of integers.
+#[derive(Clone, Debug)]
+pub struct Range<T> {
+    front: T,
+    back: T,
+    current: T,
+    valid: bool,
+}
+
+impl<T: Step> StreamingIterator for Range<T> {
+    type Item = T;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.valid = self.front < self.back;
+        if self.valid {
+            self.current = self.front;
+            self.front = self.front.forward();
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&T> {
+        if self.valid {
+            Some(&self.current)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match T::steps_between(self.front, self.back) {
+            Some(n) => (n, Some(n)),
+            None => (usize::MAX, None),
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        T::steps_between(self.front, self.back).unwrap_or(usize::MAX)
+    }
+}
+
+impl<T: Step> DoubleEndedStreamingIterator for Range<T> {
+    #[inline]
+    fn advance_back(&mut self) {
+        self.valid = self.front < self.back;
+        if self.valid {
+            self.back = self.back.backward();
+            self.current = self.back;
+        }
+    }
+}
+
 /// A simple iterator that repeats an item endlessly.
 ///
 /// Note: if the item is modified through `StreamingIteratorMut`,
@@ -661,6 +1439,65 @@ impl<T> StreamingIteratorMut for Repeat<T> {
 
 impl<T> DoubleEndedStreamingIteratorMut for Repeat<T> {}
 
+/// A simple iterator that repeats an item a fixed number of times.
+///
+/// Note: if the item is modified through `StreamingIteratorMut`,
+/// this will continue be reflected in further iterations!
+#[derive(Clone, Debug)]
+pub struct RepeatN<T> {
+    item: T,
+    remaining: usize,
+    current: bool,
+}
+
+impl<T> StreamingIterator for RepeatN<T> {
+    type Item = T;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.current = self.remaining > 0;
+        if self.current {
+            self.remaining -= 1;
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&Self::Item> {
+        if self.current {
+            Some(&self.item)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedStreamingIterator for RepeatN<T> {
+    #[inline]
+    fn advance_back(&mut self) {
+        self.advance();
+    }
+}
+
+impl<T> ExactSizeStreamingIterator for RepeatN<T> {}
+
+impl<T> StreamingIteratorMut for RepeatN<T> {
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        if self.current {
+            Some(&mut self.item)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> DoubleEndedStreamingIteratorMut for RepeatN<T> {}
+
 /// A simple iterator that endlessly returns items from a function call.
 #[derive(Clone, Debug)]
 pub struct RepeatWith<T, F> {
@@ -733,6 +1570,25 @@ impl<T, F: FnMut(T) -> Option<T>> StreamingIterator for Successors<T, F> {
             (_, &None) => (0, Some(0)),
         }
     }
+
+    #[inline]
+    fn fold<Acc, Fold>(mut self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        let mut next = if self.first {
+            self.item
+        } else {
+            self.item.and_then(&mut self.succ)
+        };
+        while let Some(item) = next {
+            acc = f(acc, &item);
+            next = (self.succ)(item);
+        }
+        acc
+    }
 }
 
 impl<T, F: FnMut(T) -> Option<T>> StreamingIteratorMut for Successors<T, F> {
@@ -740,4 +1596,95 @@ impl<T, F: FnMut(T) -> Option<T>> StreamingIteratorMut for Successors<T, F> {
     fn get_mut(&mut self) -> Option<&mut Self::Item> {
         self.item.as_mut()
     }
+
+    #[inline]
+    fn fold_mut<Acc, Fold>(mut self, init: Acc, mut f: Fold) -> Acc
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, &mut Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        let mut next = if self.first {
+            self.item
+        } else {
+            self.item.and_then(&mut self.succ)
+        };
+        while let Some(mut item) = next {
+            acc = f(acc, &mut item);
+            next = (self.succ)(item);
+        }
+        acc
+    }
+}
+
+/// An iterator where each successive item is computed from the preceding one, up to a maximum
+/// number of items.
+///
+/// Note: if an item is modified through `StreamingIteratorMut`, those changes
+/// will be visible to the successor function when the iterator is advanced.
+#[derive(Clone, Debug)]
+pub struct SuccessorsN<T, F> {
+    first: bool,
+    item: Option<T>,
+    remaining: usize,
+    succ: F,
+}
+
+impl<T, F: FnMut(T) -> Option<T>> StreamingIterator for SuccessorsN<T, F> {
+    type Item = T;
+
+    #[inline]
+    fn advance(&mut self) {
+        if self.remaining == 0 {
+            self.first = false;
+            self.item = None;
+            return;
+        }
+        self.remaining -= 1;
+        if self.first {
+            self.first = false;
+        } else if let Some(item) = self.item.take() {
+            self.item = (self.succ)(item);
+        }
+        if self.item.is_none() {
+            self.remaining = 0;
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&Self::Item> {
+        if self.first {
+            None
+        } else {
+            self.item.as_ref()
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Only the first item is known to exist before `succ` has been called.
+        let lower = if self.first {
+            core::cmp::min(self.remaining, 1)
+        } else {
+            0
+        };
+        (lower, Some(self.remaining))
+    }
 }
+
+impl<T, F: FnMut(T) -> Option<T>> StreamingIteratorMut for SuccessorsN<T, F> {
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        if self.first {
+            None
+        } else {
+            self.item.as_mut()
+        }
+    }
+}
+
+/// An iterator where each successive item is computed from the preceding one, up to a maximum
+/// number of items.
+///
+/// This is an alias of `SuccessorsN`, returned by `successors_bounded`.
+pub type SuccessorsBounded<T, F> = SuccessorsN<T, F>;